@@ -31,10 +31,12 @@
 
 use crate::rand::distributions::{Distribution, Uniform};
 use crate::rand::rngs::SmallRng;
-use crate::rand::seq::index;
+use crate::rand::seq::{index, SliceRandom};
 use crate::rand::{thread_rng, Rng, SeedableRng};
+use std::error::Error;
+use std::fmt;
 
-use ndarray::{Array, Axis, RemoveAxis, ShapeBuilder};
+use ndarray::{Array, Array1, Array2, ArrayView1, ArrayView2, Axis, RemoveAxis, ShapeBuilder};
 use ndarray::{ArrayBase, DataOwned, RawData, Data, Dimension};
 #[cfg(feature = "quickcheck")]
 use quickcheck::{Arbitrary, Gen};
@@ -313,6 +315,147 @@ impl Arbitrary for SamplingStrategy {
     }
 }
 
+/// An error from [`train_test_split`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SplitError {
+    /// `test_ratio` is not strictly between 0 and 1.
+    InvalidRatio,
+    /// The number of rows of `x` is different from the length of `y`.
+    LengthMismatch,
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match *self {
+            SplitError::InvalidRatio => "test_ratio must be between 0 and 1 (exclusive)",
+            SplitError::LengthMismatch => "the number of rows of x must equal the length of y",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+impl Error for SplitError {}
+
+/// Split the rows of `x` and the elements of `y` into a training set and a test set,
+/// after shuffling them with `rng`.
+///
+/// Return `(x_train, x_test, y_train, y_test)`. The same permutation is applied to the
+/// rows of `x` and to `y`, so row `i` of `x_train` still corresponds to `y_train[i]`.
+/// The test set gets `test_ratio` of the rows, rounded up, and the training set gets the
+/// rest. The split only depends on the state of `rng`, so a seeded RNG reproduces it.
+///
+/// ***Errors*** with [`SplitError::InvalidRatio`] if `test_ratio` is not strictly between
+/// 0 and 1, and with [`SplitError::LengthMismatch`] if the number of rows of `x` is
+/// different from the length of `y`.
+///
+/// ```
+/// use ndarray::{array, Array, Axis};
+/// use ndarray_rand::train_test_split;
+/// use ndarray_rand::rand::SeedableRng;
+/// use rand_isaac::isaac64::Isaac64Rng;
+///
+/// let x = Array::from_shape_fn((10, 2), |(i, j)| (i * 2 + j) as f64);
+/// let y = Array::from_shape_fn(10, |i| i % 2);
+/// let mut rng = Isaac64Rng::seed_from_u64(42);
+/// let (x_train, x_test, y_train, y_test) =
+///     train_test_split(&x.view(), &y.view(), 0.25, &mut rng).unwrap();
+/// assert_eq!(x_train.nrows(), 7);
+/// assert_eq!(x_test.nrows(), 3);
+/// for (row, label) in x_test.axis_iter(Axis(0)).zip(&y_test) {
+///     assert_eq!(row[0] as usize / 2 % 2, *label);
+/// }
+/// ```
+pub fn train_test_split<A, B, R>(
+    x: &ArrayView2<A>,
+    y: &ArrayView1<B>,
+    test_ratio: f64,
+    rng: &mut R,
+) -> Result<(Array2<A>, Array2<A>, Array1<B>, Array1<B>), SplitError>
+where
+    A: Copy,
+    B: Copy,
+    R: Rng + ?Sized,
+{
+    if !(test_ratio > 0. && test_ratio < 1.) {
+        return Err(SplitError::InvalidRatio);
+    }
+    let n = x.nrows();
+    if n != y.len() {
+        return Err(SplitError::LengthMismatch);
+    }
+    let n_test = (test_ratio * n as f64).ceil() as usize;
+    let mut indices: Vec<usize> = (0..n).collect();
+    indices.shuffle(rng);
+    let (test, train) = indices.split_at(n_test);
+    Ok((
+        x.select(Axis(0), train),
+        x.select(Axis(0), test),
+        y.select(Axis(0), train),
+        y.select(Axis(0), test),
+    ))
+}
+
+/// Return the `(train_indices, test_indices)` pairs of the `k` folds of k-fold
+/// cross-validation over `n_samples` samples.
+///
+/// The sample indices `0..n_samples` are shuffled with `rng` if `shuffle` is true, and
+/// then split into `k` consecutive test folds. The first `n_samples % k` folds get one
+/// sample more than the others, so the fold sizes differ by at most one. Every sample
+/// is in exactly one test fold, and the training indices of a fold are all the other
+/// samples, in increasing order. Select the samples of a fold with
+/// [`select`](ndarray::ArrayBase::select).
+///
+/// `rng` is not used if `shuffle` is false. With a seeded RNG, the folds are
+/// reproducible.
+///
+/// ***Panics*** when `k` is less than 2 or greater than `n_samples`.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_rand::kfold;
+/// use ndarray_rand::rand::SeedableRng;
+/// use rand_isaac::isaac64::Isaac64Rng;
+///
+/// let mut rng = Isaac64Rng::seed_from_u64(42);
+/// let folds = kfold(5, 2, false, &mut rng);
+/// assert_eq!(folds[0], (array![3, 4], array![0, 1, 2]));
+/// assert_eq!(folds[1], (array![0, 1, 2], array![3, 4]));
+/// ```
+pub fn kfold<R>(
+    n_samples: usize,
+    k: usize,
+    shuffle: bool,
+    rng: &mut R,
+) -> Vec<(Array1<usize>, Array1<usize>)>
+where
+    R: Rng + ?Sized,
+{
+    assert!(
+        k >= 2 && k <= n_samples,
+        "k must be at least 2 and at most n_samples = {}, got {}",
+        n_samples,
+        k
+    );
+    let mut indices: Vec<usize> = (0..n_samples).collect();
+    if shuffle {
+        indices.shuffle(rng);
+    }
+    let mut folds = Vec::with_capacity(k);
+    let mut start = 0;
+    for fold in 0..k {
+        let size = n_samples / k + (fold < n_samples % k) as usize;
+        let test = &indices[start..start + size];
+        let mut in_test = vec![false; n_samples];
+        for &i in test {
+            in_test[i] = true;
+        }
+        let train = (0..n_samples).filter(|&i| !in_test[i]).collect();
+        folds.push((Array1::from_vec(train), Array1::from(test.to_vec())));
+        start += size;
+    }
+    folds
+}
+
 fn get_rng() -> SmallRng {
     SmallRng::from_rng(thread_rng()).expect("create SmallRng from thread_rng failed")
 }
@@ -4,7 +4,9 @@ use ndarray_rand::rand::{distributions::Distribution, thread_rng};
 
 use ndarray::ShapeBuilder;
 use ndarray_rand::rand_distr::Uniform;
-use ndarray_rand::{RandomExt, SamplingStrategy};
+use ndarray_rand::rand::SeedableRng;
+use ndarray_rand::{kfold, train_test_split, RandomExt, SamplingStrategy, SplitError};
+use rand_isaac::isaac64::Isaac64Rng;
 use quickcheck::quickcheck;
 
 #[test]
@@ -132,3 +134,89 @@ fn sampling_with_replacement_from_a_zero_length_axis_should_panic() {
     let a = Array::random((0, n), Uniform::new(0., 2.));
     let _samples = a.sample_axis(Axis(0), 1, SamplingStrategy::WithReplacement);
 }
+
+#[test]
+fn train_test_split_sizes_and_alignment() {
+    let x = Array::from_shape_fn((20, 3), |(i, j)| (i * 10 + j) as f64);
+    let y = Array::from_shape_fn(20, |i| i);
+    let mut rng = Isaac64Rng::seed_from_u64(7);
+    let (x_train, x_test, y_train, y_test) = train_test_split(&x.view(), &y.view(), 0.3, &mut rng).unwrap();
+    assert_eq!(x_train.dim(), (14, 3));
+    assert_eq!(x_test.dim(), (6, 3));
+    assert_eq!((y_train.len(), y_test.len()), (14, 6));
+
+    // rows stay aligned with their targets, and each row is used exactly once
+    let mut seen: Vec<usize> = y_train.iter().chain(&y_test).cloned().collect();
+    let train = x_train.outer_iter().zip(&y_train);
+    for (row, &label) in train.chain(x_test.outer_iter().zip(&y_test)) {
+        assert_eq!(row, x.row(label));
+    }
+    seen.sort_unstable();
+    assert_eq!(seen, (0..20).collect::<Vec<_>>());
+
+    // the test set size is rounded up
+    let (_, x_test, _, _) = train_test_split(&x.view(), &y.view(), 0.01, &mut rng).unwrap();
+    assert_eq!(x_test.nrows(), 1);
+}
+
+#[test]
+fn train_test_split_is_reproducible() {
+    let x = Array::from_shape_fn((50, 2), |(i, j)| (i + j) as f64);
+    let y = Array::from_shape_fn(50, |i| i as f64);
+    let split = |seed| {
+        let mut rng = Isaac64Rng::seed_from_u64(seed);
+        train_test_split(&x.view(), &y.view(), 0.2, &mut rng).unwrap()
+    };
+    assert_eq!(split(42), split(42));
+    assert_ne!(split(42).3, split(43).3);
+}
+
+#[test]
+fn train_test_split_errors() {
+    let x = Array2::<f64>::zeros((4, 2));
+    let y = Array::<f64, _>::zeros(4);
+    let mut rng = Isaac64Rng::seed_from_u64(0);
+    for &ratio in &[0., 1., -0.5, 1.5, f64::NAN] {
+        let res = train_test_split(&x.view(), &y.view(), ratio, &mut rng);
+        assert_eq!(res, Err(SplitError::InvalidRatio));
+    }
+    let y = Array::<f64, _>::zeros(3);
+    let res = train_test_split(&x.view(), &y.view(), 0.5, &mut rng);
+    assert_eq!(res, Err(SplitError::LengthMismatch));
+}
+
+#[test]
+fn kfold_covers_each_sample_once() {
+    let mut rng = Isaac64Rng::seed_from_u64(3);
+    for &shuffle in &[false, true] {
+        let folds = kfold(11, 4, shuffle, &mut rng);
+        assert_eq!(folds.len(), 4);
+        let sizes: Vec<_> = folds.iter().map(|(_, test)| test.len()).collect();
+        assert_eq!(sizes, vec![3, 3, 3, 2]);
+
+        let mut test_counts = vec![0; 11];
+        for (train, test) in &folds {
+            assert_eq!(train.len() + test.len(), 11);
+            assert!(train.windows(2).into_iter().all(|w| w[0] < w[1]));
+            for &i in test {
+                test_counts[i] += 1;
+                assert!(!train.iter().any(|&j| j == i));
+            }
+        }
+        assert!(test_counts.iter().all(|&c| c == 1));
+    }
+    assert_eq!(kfold(4, 4, false, &mut rng)[2].1, Array::from(vec![2]));
+}
+
+#[test]
+fn kfold_is_reproducible() {
+    let folds = |seed| kfold(30, 5, true, &mut Isaac64Rng::seed_from_u64(seed));
+    assert_eq!(folds(1), folds(1));
+    assert_ne!(folds(1), folds(2));
+}
+
+#[test]
+#[should_panic]
+fn kfold_too_many_folds() {
+    kfold(3, 4, false, &mut Isaac64Rng::seed_from_u64(0));
+}
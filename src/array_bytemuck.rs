@@ -0,0 +1,33 @@
+use crate::imp_prelude::*;
+use bytemuck::Pod;
+
+/// # Methods For Arrays Of Plain Old Data
+///
+/// **Requires crate feature `"bytemuck"`**
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Pod,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Reinterpret the bytes of the array as a one-dimensional view of elements of type `B`.
+    ///
+    /// The bytes are used in memory order, so the array must be contiguous (in either
+    /// standard or Fortran layout).
+    ///
+    /// Return `None` if the array is not contiguous, if its length in bytes is not a
+    /// multiple of `size_of::<B>()`, or if its data is not aligned for `B`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[0x0102_0304u32, 0x0506_0708]);
+    /// let bytes = a.view_bytes_as::<u8>().unwrap();
+    /// assert_eq!(bytes.len(), 8);
+    /// assert_eq!(bytes.view_bytes_as::<u32>(), Some(a.view()));
+    /// ```
+    pub fn view_bytes_as<B: Pod>(&self) -> Option<ArrayView1<'_, B>> {
+        let data = self.as_slice_memory_order()?;
+        bytemuck::try_cast_slice(data).ok().map(ArrayView1::from)
+    }
+}
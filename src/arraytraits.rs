@@ -9,8 +9,9 @@
 use std::hash;
 use std::iter::FromIterator;
 use std::iter::IntoIterator;
+use std::iter::{Product, Sum};
 use std::mem;
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, Index, IndexMut, Mul};
 use alloc::vec::Vec;
 
 use crate::imp_prelude::*;
@@ -190,6 +191,114 @@ where
     }
 }
 
+#[cold]
+#[inline(never)]
+fn empty_array_reduction(op: &str) -> ! {
+    panic!("ndarray: can not {} an empty iterator of arrays, the shape is unknown", op);
+}
+
+impl<A, D> Sum for Array<A, D>
+where
+    A: Clone + Add<Output = A>,
+    D: Dimension,
+{
+    /// Sum the arrays of an iterator elementwise.
+    ///
+    /// Each array is broadcast to the shape of the first array.
+    ///
+    /// **Panics** if the iterator is empty, or if broadcasting isn’t possible.
+    ///
+    /// ```rust
+    /// use ndarray::arr2;
+    ///
+    /// let arrays = vec![arr2(&[[1, 2], [3, 4]]), arr2(&[[10, 20], [30, 40]])];
+    /// let sum: ndarray::Array2<i32> = arrays.into_iter().sum();
+    /// assert_eq!(sum, arr2(&[[11, 22], [33, 44]]));
+    /// ```
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut iter = iter;
+        let first = iter.next().unwrap_or_else(|| empty_array_reduction("sum"));
+        iter.fold(first, |mut acc, a| {
+            acc.zip_mut_with(&a, |x, y| *x = x.clone() + y.clone());
+            acc
+        })
+    }
+}
+
+impl<'a, A, S, D> Sum<&'a ArrayBase<S, D>> for Array<A, D>
+where
+    A: Clone + Add<Output = A>,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Sum the arrays of an iterator elementwise.
+    ///
+    /// Each array is broadcast to the shape of the first array.
+    ///
+    /// **Panics** if the iterator is empty, or if broadcasting isn’t possible.
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a ArrayBase<S, D>>,
+    {
+        let mut iter = iter;
+        let first = iter.next().unwrap_or_else(|| empty_array_reduction("sum"));
+        iter.fold(first.to_owned(), |mut acc, a| {
+            acc.zip_mut_with(a, |x, y| *x = x.clone() + y.clone());
+            acc
+        })
+    }
+}
+
+impl<A, D> Product for Array<A, D>
+where
+    A: Clone + Mul<Output = A>,
+    D: Dimension,
+{
+    /// Multiply the arrays of an iterator elementwise.
+    ///
+    /// Each array is broadcast to the shape of the first array.
+    ///
+    /// **Panics** if the iterator is empty, or if broadcasting isn’t possible.
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        let mut iter = iter;
+        let first = iter.next().unwrap_or_else(|| empty_array_reduction("multiply"));
+        iter.fold(first, |mut acc, a| {
+            acc.zip_mut_with(&a, |x, y| *x = x.clone() * y.clone());
+            acc
+        })
+    }
+}
+
+impl<'a, A, S, D> Product<&'a ArrayBase<S, D>> for Array<A, D>
+where
+    A: Clone + Mul<Output = A>,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Multiply the arrays of an iterator elementwise.
+    ///
+    /// Each array is broadcast to the shape of the first array.
+    ///
+    /// **Panics** if the iterator is empty, or if broadcasting isn’t possible.
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a ArrayBase<S, D>>,
+    {
+        let mut iter = iter;
+        let first = iter.next().unwrap_or_else(|| empty_array_reduction("multiply"));
+        iter.fold(first.to_owned(), |mut acc, a| {
+            acc.zip_mut_with(a, |x, y| *x = x.clone() * y.clone());
+            acc
+        })
+    }
+}
+
 impl<'a, S, D> IntoIterator for &'a ArrayBase<S, D>
 where
     D: Dimension,
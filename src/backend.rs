@@ -0,0 +1,155 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Pluggable element storage for arrays.
+
+use std::ptr::NonNull;
+use std::slice;
+
+use crate::dimension;
+use crate::dimension::offset_from_ptr_to_memory;
+use crate::error::ShapeError;
+use crate::imp_prelude::*;
+use crate::StrideShape;
+
+/// Element storage that can be plugged into an array.
+///
+/// The internal representation traits ([`RawData`], [`Data`] and so on) can not be
+/// implemented outside of ndarray. Instead, a storage backend implements this trait, and
+/// the array type [`BackendArray<B, D>`] uses the backend `B` as its data through the
+/// [`BackendRepr`] wrapper. The backend only needs to hand out a pointer to a contiguous
+/// buffer of initialized elements; the array supplies the shape and strides.
+///
+/// Arrays with backend storage have all the methods of read-only arrays, and if the
+/// backend also implements [`BackendMut`], the methods of read-write arrays.
+///
+/// A backend is used through its own representation, [`BackendRepr`], which exists next
+/// to the built-in representations like [`OwnedRepr`](crate::OwnedRepr) and
+/// [`CowRepr`](crate::CowRepr); those are not implemented in terms of this trait.
+///
+/// # Safety
+///
+/// The implementor must ensure that:
+///
+/// * `as_ptr` returns a non-null pointer, aligned for `Self::Elem`, to `len()`
+///   initialized elements (the pointer may be dangling if `len()` is zero or the
+///   element type is zero-sized).
+/// * The pointer and length never change for the lifetime of the backend, and in
+///   particular the elements are not moved when the backend value itself is moved.
+/// * The elements are not mutated by anyone else while the backend is alive.
+/// * If the backend implements `Clone`, the clone has the same length and the same
+///   elements in the same order. A clone of a read-only backend may share the storage of
+///   the original; for writable backends, see [`BackendMut`].
+pub unsafe trait Backend {
+    /// The array element type.
+    type Elem;
+
+    /// Return a pointer to the first element of the storage.
+    fn as_ptr(&self) -> *const Self::Elem;
+
+    /// Return the number of elements in the storage.
+    fn len(&self) -> usize;
+
+    /// Return whether the storage has any elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Element storage that can be plugged into an array, with writable elements.
+///
+/// See [`Backend`].
+///
+/// # Safety
+///
+/// In addition to the requirements of [`Backend`], the implementor must ensure that the
+/// backend has unique access to its elements, and that the elements may be written
+/// through the pointer returned by `as_ptr`.
+///
+/// In particular, if the backend implements `Clone`, the clone must have its own copy
+/// of the elements and must not alias the storage of the original, since arrays that
+/// are cloned from each other are written independently.
+pub unsafe trait BackendMut: Backend {}
+
+/// Storage backend representation.
+///
+/// *Don’t use this type directly—use the type alias
+/// [`BackendArray`](type.BackendArray.html) for the array type!*
+#[derive(Debug)]
+pub struct BackendRepr<B>(B);
+
+impl<B: Backend> BackendRepr<B> {
+    pub(crate) fn as_slice(&self) -> &[B::Elem] {
+        unsafe { slice::from_raw_parts(self.0.as_ptr(), self.0.len()) }
+    }
+
+    pub(crate) fn backend(&self) -> &B {
+        &self.0
+    }
+
+    pub(crate) fn from_backend(backend: B) -> Self {
+        BackendRepr(backend)
+    }
+}
+
+/// An array that uses the storage backend `B` for its elements.
+///
+/// See [`Backend`] for how to implement a storage backend.
+pub type BackendArray<B, D> = ArrayBase<BackendRepr<B>, D>;
+
+/// # Methods For Arrays With Backend Storage
+impl<A, B, D> ArrayBase<BackendRepr<B>, D>
+where
+    B: Backend<Elem = A>,
+    D: Dimension,
+{
+    /// Create an array with the given shape, using `backend` for its elements.
+    ///
+    /// **Errors** if `shape` does not correspond to the number of elements in
+    /// `backend`, or, for custom strides, if strides and dimensions can point out of bounds
+    /// of the storage.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Backend, BackendArray};
+    ///
+    /// struct Boxed(Box<[i32]>);
+    ///
+    /// unsafe impl Backend for Boxed {
+    ///     type Elem = i32;
+    ///     fn as_ptr(&self) -> *const i32 { self.0.as_ptr() }
+    ///     fn len(&self) -> usize { self.0.len() }
+    /// }
+    ///
+    /// let a = BackendArray::from_backend((2, 2), Boxed(vec![1, 2, 3, 4].into())).unwrap();
+    /// assert_eq!(a, arr2(&[[1, 2], [3, 4]]));
+    /// ```
+    pub fn from_backend<Sh>(shape: Sh, backend: B) -> Result<Self, ShapeError>
+    where
+        Sh: Into<StrideShape<D>>,
+    {
+        let shape = shape.into();
+        let data = BackendRepr::from_backend(backend);
+        let dim = shape.dim;
+        let is_custom = shape.strides.is_custom();
+        dimension::can_index_slice_with_strides(data.as_slice(), &dim, &shape.strides)?;
+        if !is_custom && dim.size() != data.as_slice().len() {
+            return Err(crate::error::incompatible_shapes(&Ix1(data.as_slice().len()), &dim));
+        }
+        let strides = shape.strides.strides_for_dim(&dim);
+        unsafe {
+            let base = data.backend().as_ptr() as *mut A;
+            let ptr = NonNull::new_unchecked(base.offset(-offset_from_ptr_to_memory(&dim, &strides)));
+            Ok(ArrayBase::from_data_ptr(data, ptr).with_strides_dim(strides, dim))
+        }
+    }
+
+    /// Return a reference to the storage backend of the array.
+    pub fn backend(&self) -> &B {
+        self.data.backend()
+    }
+}
@@ -55,6 +55,40 @@ impl<A> OwnedRepr<A> {
         self.ptr
     }
 
+    /// Reserve capacity for at least `additional` more elements, like `Vec::reserve`,
+    /// and return the (possibly moved) pointer to the start of the data.
+    pub(crate) fn reserve(&mut self, additional: usize) -> NonNull<A> {
+        self.modify_as_vec(|v| v.reserve(additional))
+    }
+
+    /// Reserve capacity for exactly `additional` more elements, like
+    /// `Vec::reserve_exact`, and return the (possibly moved) pointer to the start of
+    /// the data.
+    pub(crate) fn reserve_exact(&mut self, additional: usize) -> NonNull<A> {
+        self.modify_as_vec(|v| v.reserve_exact(additional))
+    }
+
+    /// Shrink the capacity to the length, like `Vec::shrink_to_fit`, and return the
+    /// (possibly moved) pointer to the start of the data.
+    pub(crate) fn shrink_to_fit(&mut self) -> NonNull<A> {
+        self.modify_as_vec(|v| v.shrink_to_fit())
+    }
+
+    /// Modify the allocation through a `Vec` view of it, and return the new pointer to
+    /// the start of the data.
+    ///
+    /// If `f` panics, the `Vec` is not dropped and `self` keeps the original allocation.
+    fn modify_as_vec(&mut self, f: impl FnOnce(&mut Vec<A>)) -> NonNull<A> {
+        let mut v = ManuallyDrop::new(unsafe {
+            Vec::from_raw_parts(self.ptr.as_ptr(), self.len, self.capacity)
+        });
+        f(&mut v);
+        self.ptr = nonnull::nonnull_from_vec_data(&mut v);
+        self.len = v.len();
+        self.capacity = v.capacity();
+        self.ptr
+    }
+
     /// Cast self into equivalent repr of other element type
     ///
     /// ## Safety
@@ -16,7 +16,8 @@ use std::ptr::NonNull;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use crate::{ArrayBase, CowRepr, Dimension, OwnedArcRepr, OwnedRepr, RawViewRepr, ViewRepr};
+use crate::{ArrayBase, Axis, CowRepr, Dimension, OwnedArcRepr, OwnedRepr, RawViewRepr, ViewRepr};
+use crate::backend::{Backend, BackendMut, BackendRepr};
 
 /// Array representation trait.
 ///
@@ -26,7 +27,8 @@ use crate::{ArrayBase, CowRepr, Dimension, OwnedArcRepr, OwnedRepr, RawViewRepr,
 ///
 /// ***Note:*** `RawData` is not an extension interface at this point.
 /// Traits in Rust can serve many different roles. This trait is public because
-/// it is used as a bound on public methods.
+/// it is used as a bound on public methods. To use custom element storage, implement
+/// [`Backend`](crate::Backend) instead.
 pub unsafe trait RawData: Sized {
     /// The array element type.
     type Elem;
@@ -396,6 +398,57 @@ unsafe impl<'a, A> Data for ViewRepr<&'a mut A> {
 
 unsafe impl<'a, A> DataMut for ViewRepr<&'a mut A> {}
 
+unsafe impl<B: Backend> RawData for BackendRepr<B> {
+    type Elem = B::Elem;
+    fn _data_slice(&self) -> Option<&[B::Elem]> {
+        Some(self.as_slice())
+    }
+    private_impl! {}
+}
+
+unsafe impl<B: BackendMut> RawDataMut for BackendRepr<B> {
+    #[inline]
+    fn try_ensure_unique<D>(_: &mut ArrayBase<Self, D>)
+    where
+        Self: Sized,
+        D: Dimension,
+    {
+    }
+
+    #[inline]
+    fn try_is_unique(&mut self) -> Option<bool> {
+        Some(true)
+    }
+}
+
+unsafe impl<B: Backend> Data for BackendRepr<B> {
+    fn into_owned<D>(self_: ArrayBase<Self, D>) -> ArrayBase<OwnedRepr<Self::Elem>, D>
+    where
+        Self::Elem: Clone,
+        D: Dimension,
+    {
+        self_.to_owned()
+    }
+}
+
+unsafe impl<B: BackendMut> DataMut for BackendRepr<B> {}
+
+unsafe impl<B> RawDataClone for BackendRepr<B>
+where
+    B: Backend + Clone,
+{
+    unsafe fn clone_with_ptr(&self, ptr: NonNull<Self::Elem>) -> (Self, NonNull<Self::Elem>) {
+        let u = BackendRepr::from_backend(self.backend().clone());
+        let mut new_ptr = NonNull::new_unchecked(u.backend().as_ptr() as *mut B::Elem);
+        if size_of::<B::Elem>() != 0 {
+            let our_off = (ptr.as_ptr() as isize - self.backend().as_ptr() as isize)
+                / mem::size_of::<B::Elem>() as isize;
+            new_ptr = new_ptr.offset(our_off);
+        }
+        (u, new_ptr)
+    }
+}
+
 /// Array representation trait.
 ///
 /// A representation that is a unique or shared owner of its data.
@@ -419,6 +472,18 @@ pub unsafe trait DataOwned: Data {
     /// representation, without any copying.
     #[doc(hidden)]
     fn into_shared(self) -> OwnedArcRepr<Self::Elem>;
+
+    /// Remove the lane at `index` along `axis` by compacting the storage, if the
+    /// representation and the layout of the array allow it.
+    ///
+    /// Return `true` if the lane was removed.
+    #[doc(hidden)]
+    fn try_remove_index_compact<D>(_: &mut ArrayBase<Self, D>, _: Axis, _: usize) -> bool
+    where
+        D: Dimension,
+    {
+        false
+    }
 }
 
 /// Array representation trait.
@@ -441,6 +506,13 @@ unsafe impl<A> DataOwned for OwnedRepr<A> {
     fn into_shared(self) -> OwnedArcRepr<A> {
         OwnedArcRepr(Arc::new(self))
     }
+
+    fn try_remove_index_compact<D>(self_: &mut ArrayBase<Self, D>, axis: Axis, index: usize) -> bool
+    where
+        D: Dimension,
+    {
+        self_.remove_index_compact(axis, index)
+    }
 }
 
 unsafe impl<A> DataOwned for OwnedArcRepr<A> {
@@ -160,7 +160,8 @@
 //! so it's not restricted to 1-D and 2-D vectors and matrices. Also, operators
 //! operate elementwise by default, so the multiplication operator `*` performs
 //! elementwise multiplication instead of matrix multiplication. (You have to
-//! specifically call `.dot()` if you want matrix multiplication.)
+//! specifically call `.dot()` if you want matrix multiplication, or wrap both
+//! matrices with [`.mat()`](ArrayBase::mat) to write `a.mat() * b.mat()`.)
 //!
 //! # Rough `ndarray`–NumPy equivalents
 //!
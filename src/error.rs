@@ -15,6 +15,14 @@ use std::fmt;
 pub struct ShapeError {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    detail: Option<ErrorDetail>,
+}
+
+/// Extra information about an error, included in its message.
+#[derive(Copy, Clone, Debug)]
+enum ErrorDetail {
+    /// the number of axes differs from the expected number
+    Ndim { expected: usize, actual: usize },
 }
 
 impl ShapeError {
@@ -53,7 +61,16 @@ pub enum ErrorKind {
 
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError {
-    ShapeError { repr: k }
+    ShapeError { repr: k, detail: None }
+}
+
+/// Return an `IncompatibleShape` error for an array with `actual` axes where `expected`
+/// axes are required.
+pub fn incompatible_ndim(expected: usize, actual: usize) -> ShapeError {
+    ShapeError {
+        repr: ErrorKind::IncompatibleShape,
+        detail: Some(ErrorDetail::Ndim { expected, actual }),
+    }
 }
 
 impl PartialEq for ErrorKind {
@@ -83,7 +100,13 @@ impl fmt::Display for ShapeError {
             ErrorKind::Unsupported => "unsupported operation",
             ErrorKind::Overflow => "arithmetic overflow",
         };
-        write!(f, "ShapeError/{:?}: {}", self.kind(), description)
+        write!(f, "ShapeError/{:?}: {}", self.kind(), description)?;
+        match self.detail {
+            Some(ErrorDetail::Ndim { expected, actual }) => {
+                write!(f, " (expected {} axes, got {})", expected, actual)
+            }
+            None => Ok(()),
+        }
     }
 }
 
@@ -320,3 +320,56 @@ where
 {
     arr3(xs).into_shared()
 }
+
+/// Create the cartesian product of `a` and `b` as a two-dimensional array of
+/// pairs.
+///
+/// The result has shape `(a.len(), b.len())` and the element at `[i, j]` is
+/// `(a[i], b[j])`, so iterating it in logical order visits every pair with the
+/// elements of `b` varying fastest. See [`cartesian_columns`] for the same pairs
+/// as two separate arrays.
+///
+/// **Panics** if the number of pairs overflows `isize`.
+///
+/// ```
+/// use ndarray::{aview1, cartesian};
+///
+/// let grid = cartesian(&aview1(&[1, 2]), &aview1(&['x', 'y', 'z']));
+/// assert_eq!(grid.shape(), &[2, 3]);
+/// assert_eq!(grid[[1, 2]], (2, 'z'));
+/// ```
+pub fn cartesian<A, B>(a: &ArrayView1<'_, A>, b: &ArrayView1<'_, B>) -> Array2<(A, B)>
+where
+    A: Clone,
+    B: Clone,
+{
+    Array2::from_shape_fn((a.len(), b.len()), |(i, j)| (a[i].clone(), b[j].clone()))
+}
+
+/// Create the cartesian product of `a` and `b` as two aligned one-dimensional
+/// arrays.
+///
+/// Both results have length `a.len() * b.len()`, and together the element
+/// pairs at each index enumerate the product in the same order as
+/// [`cartesian`]: each element of `a` is repeated `b.len()` times, while `b`
+/// is tiled `a.len()` times.
+///
+/// **Panics** if the number of pairs overflows `isize`.
+///
+/// ```
+/// use ndarray::{arr1, aview1, cartesian_columns};
+///
+/// let (x, y) = cartesian_columns(&aview1(&[1, 2]), &aview1(&[10., 20., 30.]));
+/// assert_eq!(x, arr1(&[1, 1, 1, 2, 2, 2]));
+/// assert_eq!(y, arr1(&[10., 20., 30., 10., 20., 30.]));
+/// ```
+pub fn cartesian_columns<A, B>(
+    a: &ArrayView1<'_, A>,
+    b: &ArrayView1<'_, B>,
+) -> (Array1<A>, Array1<B>)
+where
+    A: Clone,
+    B: Clone,
+{
+    (a.repeat_axis(Axis(0), b.len()), b.tile(a.len()))
+}
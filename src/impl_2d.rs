@@ -8,6 +8,7 @@
 
 //! Methods for two-dimensional arrays.
 use crate::imp_prelude::*;
+use num_traits::Zero;
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -121,4 +122,120 @@ where
         let (m, n) = self.dim();
         m == n
     }
+
+    /// Return a view of the matrix with the order of the columns reversed, so that it is
+    /// mirrored left to right.
+    ///
+    /// The view shares its data with `self`; see [`.flip()`](ArrayBase::flip).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(array.fliplr(), array![[2., 1.], [4., 3.]]);
+    /// ```
+    pub fn fliplr(&self) -> ArrayView2<'_, A>
+    where
+        S: Data,
+    {
+        self.flip(Axis(1))
+    }
+
+    /// Return a view of the matrix with the order of the rows reversed, so that it is
+    /// mirrored upside down.
+    ///
+    /// The view shares its data with `self`; see [`.flip()`](ArrayBase::flip).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(array.flipud(), array![[3., 4.], [1., 2.]]);
+    /// ```
+    pub fn flipud(&self) -> ArrayView2<'_, A>
+    where
+        S: Data,
+    {
+        self.flip(Axis(0))
+    }
+
+    /// Return a view of the matrix with the order of both the rows and the columns
+    /// reversed, which is the matrix rotated by 180 degrees.
+    ///
+    /// The view shares its data with `self`; see [`.flip()`](ArrayBase::flip).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(array.flip2(), array![[4., 3.], [2., 1.]]);
+    /// ```
+    pub fn flip2(&self) -> ArrayView2<'_, A>
+    where
+        S: Data,
+    {
+        self.flip_axes(&[Axis(0), Axis(1)])
+    }
+
+    /// Return a copy of the upper triangle of the matrix, with the elements
+    /// below the `k`-th diagonal set to zero.
+    ///
+    /// As in NumPy, `k = 0` is the main diagonal, `k > 0` a diagonal above it and
+    /// `k < 0` a diagonal below it. The matrix does not need to be square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// assert_eq!(a.triu(0), array![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+    /// assert_eq!(a.triu(1), array![[0, 2, 3], [0, 0, 6], [0, 0, 0]]);
+    /// assert_eq!(a.triu(-1), array![[1, 2, 3], [4, 5, 6], [0, 8, 9]]);
+    /// ```
+    pub fn triu(&self, k: isize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let (m, n) = self.dim();
+        let mut res = Array2::zeros((m, n));
+        for i in 0..m {
+            let start = diagonal_column(i, k).min(n);
+            res.slice_mut(s![i, start..])
+                .assign(&self.slice(s![i, start..]));
+        }
+        res
+    }
+
+    /// Return a copy of the lower triangle of the matrix, with the elements
+    /// above the `k`-th diagonal set to zero.
+    ///
+    /// As in NumPy, `k = 0` is the main diagonal, `k > 0` a diagonal above it and
+    /// `k < 0` a diagonal below it. The matrix does not need to be square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// assert_eq!(a.tril(0), array![[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+    /// assert_eq!(a.tril(1), array![[1, 2, 0], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a.tril(-1), array![[0, 0, 0], [4, 0, 0], [7, 8, 0]]);
+    /// ```
+    pub fn tril(&self, k: isize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let (m, n) = self.dim();
+        let mut res = Array2::zeros((m, n));
+        for i in 0..m {
+            let end = diagonal_column(i, k.saturating_add(1)).min(n);
+            res.slice_mut(s![i, ..end])
+                .assign(&self.slice(s![i, ..end]));
+        }
+        res
+    }
+}
+
+/// Return the column where the `k`-th diagonal crosses row `i`, clamped to zero.
+fn diagonal_column(i: usize, k: isize) -> usize {
+    if k >= 0 {
+        i.saturating_add(k as usize)
+    } else {
+        i.saturating_sub(k.wrapping_neg() as usize)
+    }
 }
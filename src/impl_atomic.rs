@@ -0,0 +1,97 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicI16, AtomicI32, AtomicI8, AtomicIsize};
+use std::sync::atomic::{AtomicU16, AtomicU32, AtomicU8, AtomicUsize};
+
+use crate::imp_prelude::*;
+use crate::NdIndex;
+
+/// Atomic integer types that support lock-free addition.
+///
+/// This trait is implemented for the atomic integer types of the standard library, so
+/// that an array of them can be used as a shared accumulator, see
+/// [`.fetch_add_at()`](ArrayBase::fetch_add_at).
+pub trait AtomicAdd {
+    /// The underlying integer type.
+    type Value;
+
+    /// Add `value` to the current value, wrapping around on overflow, and return the
+    /// previous value.
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+}
+
+macro_rules! impl_atomic_add {
+    ($($atomic:ty => $value:ty),* $(,)?) => {
+        $(
+        impl AtomicAdd for $atomic {
+            type Value = $value;
+
+            #[inline]
+            fn fetch_add(&self, value: $value, order: Ordering) -> $value {
+                <$atomic>::fetch_add(self, value, order)
+            }
+        }
+        )*
+    }
+}
+
+impl_atomic_add! {
+    AtomicU8 => u8,
+    AtomicU16 => u16,
+    AtomicU32 => u32,
+    AtomicUsize => usize,
+    AtomicI8 => i8,
+    AtomicI16 => i16,
+    AtomicI32 => i32,
+    AtomicIsize => isize,
+}
+
+// 64-bit atomics are not available on all targets
+#[cfg(target_has_atomic = "64")]
+impl_atomic_add! {
+    std::sync::atomic::AtomicU64 => u64,
+    std::sync::atomic::AtomicI64 => i64,
+}
+
+/// # Methods For Arrays With Atomic Elements
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: AtomicAdd,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Atomically add `value` to the element at `index`, and return the previous value of
+    /// the element.
+    ///
+    /// This only needs a shared reference to the array, so many threads can accumulate
+    /// into the same array without locks, for example when building a histogram in
+    /// parallel. The addition uses relaxed ordering; the results are visible to other
+    /// threads once they synchronize with the writing threads, for example by joining them.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// let hist = Array::from_shape_simple_fn(4, || AtomicU32::new(0));
+    /// for &x in &[1, 3, 3, 0, 3] {
+    ///     hist.fetch_add_at(x, 1);
+    /// }
+    /// let counts = hist.map(|c| c.load(Ordering::Relaxed));
+    /// assert_eq!(counts.as_slice().unwrap(), &[1, 1, 0, 3]);
+    /// ```
+    pub fn fetch_add_at<I>(&self, index: I, value: A::Value) -> A::Value
+    where
+        I: NdIndex<D>,
+    {
+        self[index].fetch_add(value, Ordering::Relaxed)
+    }
+}
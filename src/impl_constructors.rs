@@ -16,6 +16,7 @@ use num_traits::Float;
 use num_traits::{One, Zero};
 use std::mem;
 use std::mem::MaybeUninit;
+use std::ops::{Div, Neg};
 use alloc::vec;
 use alloc::vec::Vec;
 
@@ -29,7 +30,7 @@ use crate::indices;
 #[cfg(feature = "std")]
 use crate::iterators::to_vec;
 use crate::iterators::to_vec_mapped;
-use crate::StrideShape;
+use crate::{StrideShape, Zip};
 #[cfg(feature = "std")]
 use crate::{geomspace, linspace, logspace};
 use rawpointer::PointerExt;
@@ -229,6 +230,153 @@ where
         arr.diag_mut().assign(&diag);
         arr
     }
+
+    /// Create a Toeplitz matrix, which is constant along each diagonal, from its first
+    /// column and its first row.
+    ///
+    /// The result has shape `first_col.len()` × `first_row.len()`, and element `[i, j]` is
+    /// `first_col[i - j]` on and below the main diagonal and `first_row[j - i]` above it.
+    /// So the main diagonal is `first_col[0]` and `first_row[0]` is not used; it is
+    /// usually equal to `first_col[0]`.
+    ///
+    /// **Panics** if the number of elements would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{Array2, arr1, arr2};
+    ///
+    /// let array = Array2::toeplitz(&arr1(&[1, 2, 3]), &arr1(&[1, 4, 5, 6]));
+    /// assert_eq!(array, arr2(&[[1, 4, 5, 6],
+    ///                          [2, 1, 4, 5],
+    ///                          [3, 2, 1, 4]]));
+    /// ```
+    pub fn toeplitz<S2, S3>(first_col: &ArrayBase<S2, Ix1>, first_row: &ArrayBase<S3, Ix1>) -> Self
+    where
+        A: Clone,
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+    {
+        Self::from_shape_fn((first_col.len(), first_row.len()), |(i, j)| {
+            if i >= j {
+                first_col[i - j].clone()
+            } else {
+                first_row[j - i].clone()
+            }
+        })
+    }
+
+    /// Create a square circulant matrix from its first column.
+    ///
+    /// Each column is the previous column rotated down by one element, so element
+    /// `[i, j]` is `c[(i - j) mod n]` where `n` is `c.len()`. A circulant matrix is a
+    /// Toeplitz matrix.
+    ///
+    /// **Panics** if `c.len() * c.len()` would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{Array2, arr1, arr2};
+    ///
+    /// let array = Array2::circulant(&arr1(&[1, 2, 3]));
+    /// assert_eq!(array, arr2(&[[1, 3, 2],
+    ///                          [2, 1, 3],
+    ///                          [3, 2, 1]]));
+    /// ```
+    pub fn circulant<S2>(c: &ArrayBase<S2, Ix1>) -> Self
+    where
+        A: Clone,
+        S2: Data<Elem = A>,
+    {
+        let n = c.len();
+        Self::from_shape_fn((n, n), |(i, j)| c[(n + i - j) % n].clone())
+    }
+
+    /// Create a Hankel matrix, which is constant along each anti-diagonal, from its first
+    /// column and its last row.
+    ///
+    /// With `m` as `first_col.len()`, the result has shape `m` × `last_row.len()`, and
+    /// element `[i, j]` is `first_col[i + j]` on and above the main anti-diagonal
+    /// (`i + j < m`) and `last_row[i + j + 1 - m]` below it. So the last row starts with
+    /// `first_col[m - 1]` and `last_row[0]` is not used; it is usually equal to
+    /// `first_col[m - 1]`.
+    ///
+    /// If `last_row` is `None`, the result is square and the elements below the main
+    /// anti-diagonal are zero.
+    ///
+    /// **Panics** if the number of elements would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{Array2, arr1, arr2};
+    ///
+    /// let col = arr1(&[1, 2, 3]);
+    /// assert_eq!(Array2::hankel(&col, None), arr2(&[[1, 2, 3],
+    ///                                                [2, 3, 0],
+    ///                                                [3, 0, 0]]));
+    ///
+    /// let row = arr1(&[3, 4, 5, 6]);
+    /// assert_eq!(Array2::hankel(&col, Some(&row.view())), arr2(&[[1, 2, 3, 4],
+    ///                                                             [2, 3, 4, 5],
+    ///                                                             [3, 4, 5, 6]]));
+    /// ```
+    pub fn hankel<S2>(first_col: &ArrayBase<S2, Ix1>, last_row: Option<&ArrayView1<'_, A>>) -> Self
+    where
+        A: Clone + Zero,
+        S2: Data<Elem = A>,
+    {
+        let m = first_col.len();
+        let n = last_row.map_or(m, |row| row.len());
+        Self::from_shape_fn((m, n), |(i, j)| {
+            if i + j < m {
+                first_col[i + j].clone()
+            } else {
+                match last_row {
+                    Some(row) => row[i + j + 1 - m].clone(),
+                    None => A::zero(),
+                }
+            }
+        })
+    }
+
+    /// Create the companion matrix of the polynomial with coefficients `coeffs`.
+    ///
+    /// The coefficients are given highest degree first, like for
+    /// [`polyval`](crate::polyval). For a polynomial of degree `n`, the result is the
+    /// `n` × `n` matrix whose first row is `-coeffs[1..] / coeffs[0]`, with ones on the
+    /// subdiagonal and zeros elsewhere. Its characteristic polynomial is the polynomial
+    /// divided by its leading coefficient, so its eigenvalues are the roots of the
+    /// polynomial.
+    ///
+    /// **Panics** if `coeffs` has less than two elements or if `coeffs[0]` is zero.
+    ///
+    /// ```rust
+    /// use ndarray::{Array2, arr1, arr2};
+    ///
+    /// // x² - 3x + 2 = (x - 1)(x - 2)
+    /// let array = Array2::companion(&arr1(&[1., -3., 2.]));
+    /// assert_eq!(array, arr2(&[[3., -2.],
+    ///                          [1., 0.]]));
+    /// ```
+    pub fn companion<S2>(coeffs: &ArrayBase<S2, Ix1>) -> Self
+    where
+        A: Clone + Zero + One + Neg<Output = A> + Div<Output = A>,
+        S: DataMut,
+        S2: Data<Elem = A>,
+    {
+        assert!(
+            coeffs.len() >= 2,
+            "ndarray: companion requires a polynomial of degree at least one, got {} coefficients",
+            coeffs.len()
+        );
+        assert!(
+            !coeffs[0].is_zero(),
+            "ndarray: companion requires a nonzero leading coefficient"
+        );
+        let n = coeffs.len() - 1;
+        let mut arr = Self::zeros((n, n));
+        Zip::from(arr.row_mut(0))
+            .and(coeffs.slice(s![1..]))
+            .for_each(|a, c| *a = -(c.clone() / coeffs[0].clone()));
+        arr.slice_mut(s![1.., ..-1]).diag_mut().fill(A::one());
+        arr
+    }
 }
 
 #[cfg(not(debug_assertions))]
@@ -6,9 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::mem::{size_of, ManuallyDrop};
+use std::mem::{size_of, size_of_val, ManuallyDrop, MaybeUninit};
 use alloc::slice;
-use alloc::vec;
 use alloc::vec::Vec;
 use rawpointer::PointerExt;
 
@@ -26,7 +25,7 @@ use crate::dimension::broadcast::co_broadcast;
 use crate::error::{self, ErrorKind, ShapeError, from_kind};
 use crate::math_cell::MathCell;
 use crate::itertools::zip;
-use crate::zip::{IntoNdProducer, Zip};
+use crate::zip::{FoldWhile, IntoNdProducer, Zip};
 use crate::AxisDescription;
 
 use crate::iter::{
@@ -34,7 +33,6 @@ use crate::iter::{
     IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
 };
 use crate::slice::{MultiSliceArg, SliceArg};
-use crate::stacking::concatenate;
 use crate::{NdIndex, Slice, SliceInfoElem};
 
 /// # Methods For All Array Types
@@ -332,6 +330,90 @@ where
         IndexedIterMut::new(self.view_mut().into_elements_base())
     }
 
+    /// Return the indices of the elements for which `f` returns `true`.
+    ///
+    /// The result is a two-dimensional array with one row per matching element,
+    /// holding its index along each axis of `self`. The rows are in logical
+    /// (row-major) order of the elements.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[0, 5],
+    ///                [7, 1]]);
+    /// assert_eq!(a.argwhere(|&x| x > 3), arr2(&[[0, 1], [1, 0]]));
+    /// ```
+    pub fn argwhere<F>(&self, mut f: F) -> Array2<usize>
+    where
+        F: FnMut(&A) -> bool,
+        S: Data,
+    {
+        let mut n = 0;
+        let mut indices = Vec::new();
+        for (index, elt) in self.indexed_iter() {
+            if f(elt) {
+                n += 1;
+                indices.extend_from_slice(index.into_dimension().slice());
+            }
+        }
+        Array2::from_shape_vec((n, self.ndim()), indices).unwrap()
+    }
+
+    /// Call `f` by mutable reference on each element at the given `indices`.
+    ///
+    /// The elements are visited in the order of `indices`; an index that is listed more
+    /// than once is visited more than once. The other elements are not visited.
+    ///
+    /// **Panics** if any of the indices is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let mut a = Array2::<i32>::zeros((2, 3));
+    /// a.apply_at_indices(&[(0, 1), (1, 2), (0, 1)], |x| *x += 1);
+    /// assert_eq!(a, arr2(&[[0, 2, 0], [0, 0, 1]]));
+    /// ```
+    pub fn apply_at_indices<F>(&mut self, indices: &[D::Pattern], mut f: F)
+    where
+        F: FnMut(&mut A),
+        S: DataMut,
+    {
+        for index in indices {
+            f(&mut self[index.clone().into_dimension()]);
+        }
+    }
+
+    /// Set every element for which `pred` returns `true` to `value`, and return the
+    /// number of elements that were set.
+    ///
+    /// This is done in a single pass, without creating a mask array.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[-1., 2.],
+    ///                    [3., -4.]]);
+    /// assert_eq!(a.mask_where(|&x| x < 0., 0.), 2);
+    /// assert_eq!(a, arr2(&[[0., 2.], [3., 0.]]));
+    /// ```
+    pub fn mask_where<F>(&mut self, mut pred: F, value: A) -> usize
+    where
+        F: FnMut(&A) -> bool,
+        A: Clone,
+        S: DataMut,
+    {
+        let mut count = 0;
+        self.map_inplace(|elt| {
+            if pred(elt) {
+                *elt = value.clone();
+                count += 1;
+            }
+        });
+        count
+    }
+
     /// Return a sliced view of the array.
     ///
     /// See [*Slicing*](#slicing) for full documentation.
@@ -853,6 +935,10 @@ where
     /// Along `axis`, select arbitrary subviews corresponding to `indices`
     /// and and copy them into a new array.
     ///
+    /// Subview `indices[i]` is copied to position `i` along `axis` of the result, so
+    /// indices may repeat and appear in any order; this can be used for reordering,
+    /// shuffling or resampling along an axis.
+    ///
     /// **Panics** if `axis` or an element of `indices` is out of bounds.
     ///
     /// ```
@@ -873,21 +959,268 @@ where
     /// ```
     pub fn select(&self, axis: Axis, indices: &[Ix]) -> Array<A, D>
     where
-        A: Copy,
+        A: Clone,
         S: Data,
         D: RemoveAxis,
     {
-        let mut subs = vec![self.view(); indices.len()];
-        for (&i, sub) in zip(indices, &mut subs[..]) {
-            sub.collapse_axis(axis, i);
+        let axis_len = self.len_of(axis);
+        if let Some(&max_index) = indices.iter().max() {
+            assert!(
+                max_index < axis_len,
+                "ndarray: select index {} is out of bounds for axis {} of length {}",
+                max_index,
+                axis.index(),
+                axis_len
+            );
         }
-        if subs.is_empty() {
-            let mut dim = self.raw_dim();
-            dim.set_axis(axis, 0);
-            unsafe { Array::from_shape_vec_unchecked(dim, vec![]) }
-        } else {
-            concatenate(axis, &subs).unwrap()
+        let mut dim = self.raw_dim();
+        dim.set_axis(axis, indices.len());
+        let mut res = Array::uninit(dim);
+        for (&index, sub) in zip(indices, res.axis_iter_mut(axis)) {
+            self.index_axis(axis, index).assign_to(sub);
+        }
+        // safe because every subview along `axis` was assigned
+        unsafe { res.assume_init() }
+    }
+
+    /// Along `axis`, select elements of each lane by the corresponding lane of `indices`
+    /// and copy them into a new array.
+    ///
+    /// The result has the shape of `self`, except along `axis` where it has the length
+    /// of `indices`. Element `i` of a lane of `indices` selects which element of the
+    /// corresponding lane of `self` goes to position `i` of the lane of the result; for
+    /// 2D arrays and `Axis(1)`, `result[[r, j]]` is `self[[r, indices[[r, j]]]]`.
+    /// `indices` is broadcast to the shape of the result, so its length along the other
+    /// axes can also be one.
+    ///
+    /// This pairs with index arrays that are computed per lane, such as the result of
+    /// sorting or finding the maximum of each lane.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `indices` can't be broadcast to the shape
+    /// of the result, or if an element of `indices` is out of bounds for `axis`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let scores = arr2(&[[0.1, 0.7, 0.2],
+    ///                     [0.5, 0.3, 0.9]]);
+    /// let top = arr2(&[[1, 2],
+    ///                  [2, 0]]);
+    /// assert_eq!(
+    ///     scores.take_along_axis(&top, Axis(1)),
+    ///     arr2(&[[0.7, 0.2],
+    ///            [0.9, 0.5]])
+    /// );
+    /// ```
+    pub fn take_along_axis<S2>(&self, indices: &ArrayBase<S2, D>, axis: Axis) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = usize>,
+    {
+        let axis_len = self.len_of(axis);
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = indices.len_of(axis);
+        let indices = match indices.broadcast(dim.clone()) {
+            Some(indices) => indices,
+            None => panic!(
+                "ndarray: take_along_axis could not broadcast indices of shape {:?} to shape {:?}",
+                indices.shape(),
+                dim.slice()
+            ),
+        };
+        if let Some(&max_index) = indices.iter().max() {
+            assert!(
+                max_index < axis_len,
+                "ndarray: take_along_axis index {} is out of bounds for axis {} of length {}",
+                max_index,
+                axis.index(),
+                axis_len
+            );
+        }
+        let mut res = Array::uninit(dim);
+        Zip::from(res.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(self.lanes(axis))
+            .for_each(|res_lane, index_lane, lane| {
+                Zip::from(res_lane)
+                    .and(index_lane)
+                    .for_each(|elt, &i| *elt = MaybeUninit::new(lane[i].clone()));
+            });
+        // safe because every element was assigned
+        unsafe { res.assume_init() }
+    }
+
+    /// Return a copy of the array with the elements shifted circularly by `shift` along
+    /// `axis`.
+    ///
+    /// The element at position `i` along `axis` moves to position
+    /// `(i + shift) mod len_of(axis)`, so a negative `shift` shifts towards the start of
+    /// the axis. Elements shifted past one end of the axis reappear at the other end.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr1(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(a.roll_axis(Axis(0), 2), arr1(&[4, 5, 1, 2, 3]));
+    /// assert_eq!(a.roll_axis(Axis(0), -1), arr1(&[2, 3, 4, 5, 1]));
+    ///
+    /// let b = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(b.roll_axis(Axis(1), 1), arr2(&[[3, 1, 2],
+    ///                                           [6, 4, 5]]));
+    /// ```
+    pub fn roll_axis(&self, axis: Axis, shift: isize) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let len = self.len_of(axis);
+        let k = roll_amount(len, shift);
+        let mut res = Array::uninit(self.raw_dim());
+        {
+            let (front, back) = res.view_mut().split_at(axis, k);
+            self.slice_axis(axis, Slice::from(len - k..)).assign_to(front);
+            self.slice_axis(axis, Slice::from(..len - k)).assign_to(back);
+        }
+        // safe because both parts of the array were assigned
+        unsafe { res.assume_init() }
+    }
+
+    /// Shift the elements circularly by `shift` along `axis`, in place.
+    ///
+    /// See [`.roll_axis()`](ArrayBase::roll_axis). Lanes along `axis` that are
+    /// contiguous in memory are rotated as slices, and other lanes by swapping elements,
+    /// so the elements don't need to be `Clone`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[1, 2, 3],
+    ///                    [4, 5, 6]]);
+    /// a.roll_axis_inplace(Axis(1), -1);
+    /// assert_eq!(a, arr2(&[[2, 3, 1],
+    ///                      [5, 6, 4]]));
+    /// a.roll_axis_inplace(Axis(0), 1);
+    /// assert_eq!(a, arr2(&[[5, 6, 4],
+    ///                      [2, 3, 1]]));
+    /// ```
+    pub fn roll_axis_inplace(&mut self, axis: Axis, shift: isize)
+    where
+        S: DataMut,
+    {
+        let len = self.len_of(axis);
+        let k = roll_amount(len, shift);
+        if k == 0 {
+            return;
+        }
+        for mut lane in self.lanes_mut(axis) {
+            match lane.as_slice_mut() {
+                Some(slc) => slc.rotate_right(k),
+                None => {
+                    // rotate by reversing the whole lane and then each of its two parts
+                    reverse_lane(&mut lane.view_mut());
+                    reverse_lane(&mut lane.slice_mut(s![..k]));
+                    reverse_lane(&mut lane.slice_mut(s![k..]));
+                }
+            }
+        }
+    }
+
+    /// Return an array made of copies of the whole array, repeated `reps[k]` times along
+    /// each axis `k`.
+    ///
+    /// The result has length `len_of(Axis(k)) * reps[k]` along axis `k`. This is like
+    /// NumPy's `tile`: the array is copied as a block, so `[1, 2]` tiled twice is
+    /// `[1, 2, 1, 2]`. In contrast, [`.repeat_axis()`](ArrayBase::repeat_axis) repeats
+    /// each element in place, which gives `[1, 1, 2, 2]`.
+    ///
+    /// **Panics** if the number of elements of the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr1(&[1, 2]);
+    /// assert_eq!(a.tile(3), arr1(&[1, 2, 1, 2, 1, 2]));
+    ///
+    /// let b = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(b.tile((2, 3)), arr2(&[[1, 2, 1, 2, 1, 2],
+    ///                                  [3, 4, 3, 4, 3, 4],
+    ///                                  [1, 2, 1, 2, 1, 2],
+    ///                                  [3, 4, 3, 4, 3, 4]]));
+    /// ```
+    pub fn tile<E>(&self, reps: E) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        E: IntoDimension<Dim = D>,
+    {
+        let reps = reps.into_dimension();
+        let mut dim = self.raw_dim();
+        for (len, &rep) in zip(dim.slice_mut(), reps.slice()) {
+            *len = len.checked_mul(rep).unwrap_or_else(|| {
+                panic!("ndarray: tile result with {} repetitions overflows isize", rep)
+            });
         }
+        let mut res = Array::uninit(dim);
+        if self.is_empty() {
+            // safe because the result is empty too
+            return unsafe { res.assume_init() };
+        }
+        let mut tile_index = reps.first_index();
+        while let Some(index) = tile_index {
+            let block = res.slice_each_axis_mut(|ax| {
+                let len = self.len_of(ax.axis);
+                let start = index[ax.axis.index()] * len;
+                Slice::from(start..start + len)
+            });
+            self.assign_to(block);
+            tile_index = reps.next_for(index);
+        }
+        // safe because the tiles cover the whole result
+        unsafe { res.assume_init() }
+    }
+
+    /// Return an array where each subview along `axis` is repeated `repeats` times in a
+    /// row.
+    ///
+    /// The result has length `len_of(axis) * repeats` along `axis`, and the same length
+    /// as `self` along the other axes. This is like NumPy's `repeat`: each element is
+    /// repeated in place, so `[1, 2]` repeated twice is `[1, 1, 2, 2]`. In contrast,
+    /// [`.tile()`](ArrayBase::tile) copies the whole array as a block, which gives
+    /// `[1, 2, 1, 2]`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the number of elements of the result
+    /// would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr1(&[1, 2]);
+    /// assert_eq!(a.repeat_axis(Axis(0), 3), arr1(&[1, 1, 1, 2, 2, 2]));
+    ///
+    /// let b = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(b.repeat_axis(Axis(1), 2), arr2(&[[1, 1, 2, 2],
+    ///                                             [3, 3, 4, 4]]));
+    /// ```
+    pub fn repeat_axis(&self, axis: Axis, repeats: usize) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let len = self.len_of(axis);
+        let new_len = len
+            .checked_mul(repeats)
+            .expect("ndarray: repeat_axis result length overflows usize");
+        let indices: Vec<usize> = (0..new_len).map(|i| i / repeats).collect();
+        self.select(axis, &indices)
     }
 
     /// Return a producer and iterable that traverses over the *generalized*
@@ -1290,6 +1623,73 @@ where
         Windows::new(self.view(), window_size)
     }
 
+    /// Call `f` on a mutable view of each window of size `window_size` in the array, in
+    /// turn.
+    ///
+    /// The windows are the same as those of [`.windows()`](#method.windows): all
+    /// windows of size `window_size` that fit in the array, with a step of one along each
+    /// axis. Since neighbouring windows overlap, `f` gets one window at a time and can't
+    /// keep it, so the windows never alias. They are visited in logical order of their
+    /// first element, so the changes made to one window are visible in the following
+    /// overlapping windows.
+    ///
+    /// If the array is smaller than `window_size` along any axis, `f` is not called.
+    ///
+    /// **Panics** if any dimension of `window_size` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` does not match the
+    /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // running maximum, from left to right
+    /// let mut a = array![1, 3, 2, 0, 5, 4];
+    /// a.for_each_window_mut(2, |mut w| {
+    ///     if w[1] < w[0] {
+    ///         w[1] = w[0];
+    ///     }
+    /// });
+    /// assert_eq!(a, array![1, 3, 3, 3, 5, 5]);
+    /// ```
+    pub fn for_each_window_mut<E, F>(&mut self, window_size: E, mut f: F)
+    where
+        E: IntoDimension<Dim = D>,
+        F: FnMut(ArrayViewMut<'_, A, D>),
+        S: DataMut,
+    {
+        let window = window_size.into_dimension();
+        ndassert!(
+            self.ndim() == window.ndim(),
+            concat!(
+                "Window dimension {} does not match array dimension {} ",
+                "(with array of shape {:?})"
+            ),
+            window.ndim(),
+            self.ndim(),
+            self.shape()
+        );
+        // The number of window positions along each axis, like in `Windows`
+        let mut size = self.raw_dim();
+        for (sz, &ws) in size.slice_mut().iter_mut().zip(window.slice()) {
+            assert_ne!(ws, 0, "window-size must not be zero!");
+            *sz = if *sz < ws { 0 } else { *sz - ws + 1 };
+        }
+
+        let mut view = self.view_mut();
+        let strides = view.strides.clone();
+        let ptr = view.as_mut_ptr();
+        let mut index = size.first_index();
+        while let Some(ix) = index {
+            // Safety: the window is inside the array, and `f` can't keep the view, so it
+            // does not alias the next window.
+            unsafe {
+                let offset = D::stride_offset(&ix, &strides);
+                f(ArrayViewMut::new_(ptr.offset(offset), window.clone(), strides.clone()));
+            }
+            index = size.next_for(ix);
+        }
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs) {
         /* empty shape has len 1 */
@@ -1317,6 +1717,27 @@ where
         self.view_mut().into_diag()
     }
 
+    /// Set all diagonal elements of the array to `value`; see
+    /// [`.diag_mut()`](ArrayBase::diag_mut).
+    ///
+    /// For a non-square array, the diagonal has the length of the shortest axis.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let mut a = Array2::<f64>::zeros((2, 3));
+    /// a.fill_diagonal(1.);
+    /// assert_eq!(a, arr2(&[[1., 0., 0.],
+    ///                      [0., 1., 0.]]));
+    /// ```
+    pub fn fill_diagonal(&mut self, value: A)
+    where
+        S: DataMut,
+        A: Clone,
+    {
+        self.diag_mut().fill(value)
+    }
+
     /// Return the diagonal as a one-dimensional array.
     pub fn into_diag(self) -> ArrayBase<S, Ix1> {
         let (len, stride) = self.diag_params();
@@ -1640,6 +2061,139 @@ where
         }
     }
 
+    /// Return the array reshaped to `shape`, where the number of axes is only
+    /// known at runtime, cloning the data if necessary.
+    ///
+    /// Elements are taken in logical order (row-major), whatever the memory
+    /// layout of `self`. If `self` is in standard layout, a COW view of the data
+    /// is returned without cloning. Otherwise, the data is cloned in logical
+    /// order, and the returned array owns the cloned data.
+    ///
+    /// ***Errors*** if the shapes don't have the same number of elements.
+    ///
+    /// ```
+    /// use ndarray::{arr2, ArrayD, IxDyn};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]).into_dyn();
+    /// let b = a.reshape_dyn(&[3, 2]).unwrap();
+    /// assert!(b.is_view());
+    /// assert_eq!(b, arr2(&[[1, 2], [3, 4], [5, 6]]).into_dyn());
+    /// assert!(a.reshape_dyn(&[4, 2]).is_err());
+    ///
+    /// let t = a.t();
+    /// let c = t.reshape_dyn(&[6]).unwrap();
+    /// assert!(c.is_owned());
+    /// assert_eq!(c, ArrayD::from_shape_vec(IxDyn(&[6]), vec![1, 4, 2, 5, 3, 6]).unwrap());
+    /// ```
+    pub fn reshape_dyn(&self, shape: &[usize]) -> Result<CowArray<'_, A, IxDyn>, ShapeError>
+    where
+        S: Data,
+        A: Clone,
+    {
+        let shape = IxDyn(shape);
+        if size_of_shape_checked(&shape) != Ok(self.dim.size()) {
+            return Err(error::incompatible_shapes(&self.dim, &shape));
+        }
+        if self.is_standard_layout() {
+            // safe because the view is contiguous and len is unchanged
+            unsafe {
+                let view = self.view().with_strides_dim(shape.default_strides(), shape);
+                Ok(CowArray::from(view))
+            }
+        } else {
+            let v = self.iter().cloned().collect::<Vec<A>>();
+            unsafe { Ok(CowArray::from(Array::from_shape_vec_unchecked(shape, v))) }
+        }
+    }
+
+    /// Return the array reshaped to `shape`, where at most one axis length may
+    /// be given as `-1` and is then inferred from the number of elements,
+    /// cloning the data if necessary; see [`.reshape_dyn()`](ArrayBase::reshape_dyn).
+    ///
+    /// ***Errors*** if `shape` contains more than one `-1` or any other negative
+    /// length.<br>
+    /// ***Errors*** if the inferred length is not a whole number, or cannot be
+    /// determined because the other lengths have a zero product.<br>
+    /// ***Errors*** if the shapes don't have the same number of elements.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_iter(0..24).into_dyn();
+    /// let b = a.reshape_infer(&[2, -1, 4]).unwrap();
+    /// assert_eq!(b.shape(), &[2, 3, 4]);
+    /// assert!(a.reshape_infer(&[5, -1]).is_err());
+    /// ```
+    pub fn reshape_infer(&self, shape: &[isize]) -> Result<CowArray<'_, A, IxDyn>, ShapeError>
+    where
+        S: Data,
+        A: Clone,
+    {
+        let mut inferred = None;
+        let mut known = 1usize;
+        let mut dims = Vec::with_capacity(shape.len());
+        for (i, &len) in shape.iter().enumerate() {
+            if len == -1 {
+                if inferred.is_some() {
+                    return Err(from_kind(ErrorKind::IncompatibleShape));
+                }
+                inferred = Some(i);
+                dims.push(0);
+            } else if len < 0 {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            } else {
+                known = known
+                    .checked_mul(len as usize)
+                    .ok_or_else(|| from_kind(ErrorKind::Overflow))?;
+                dims.push(len as usize);
+            }
+        }
+        if let Some(i) = inferred {
+            if known == 0 {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            }
+            // an indivisible length is caught by the size check in reshape_dyn
+            dims[i] = self.len() / known;
+        }
+        self.reshape_dyn(&dims)
+    }
+
+    /// Return a read-only view of the array with dynamic dimensionality.
+    ///
+    /// This is the same as `.view().into_dyn()`; the view has the same shape and
+    /// strides as `self`, and nothing is copied. It is useful for passing arrays of any
+    /// dimensionality to functions that take an `ArrayViewD`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, ArrayViewD};
+    ///
+    /// fn count_axes(a: ArrayViewD<'_, i32>) -> usize {
+    ///     a.ndim()
+    /// }
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(count_axes(a.as_dyn()), 2);
+    /// ```
+    pub fn as_dyn(&self) -> ArrayViewD<'_, A>
+    where
+        S: Data,
+    {
+        self.view().into_dyn()
+    }
+
+    /// Return a read-write view of the array with dynamic dimensionality.
+    ///
+    /// This is the same as `.view_mut().into_dyn()`; see
+    /// [`.as_dyn()`](ArrayBase::as_dyn).
+    pub fn as_dyn_mut(&mut self) -> ArrayViewMutD<'_, A>
+    where
+        S: DataMut,
+    {
+        self.view_mut().into_dyn()
+    }
+
     /// Convert any array or array view to a dynamic dimensional array or
     /// array view (respectively).
     ///
@@ -1663,6 +2217,9 @@ where
     /// Note that conversion to a dynamic dimensional array will never fail (and is equivalent to
     /// the `into_dyn` method).
     ///
+    /// ***Errors*** with `ErrorKind::IncompatibleShape` if the number of axes doesn't match;
+    /// the message of the error includes the expected and the actual number of axes.
+    ///
     /// ```
     /// use ndarray::{ArrayD, Ix2, IxDyn};
     ///
@@ -1672,6 +2229,13 @@ where
     /// let array = ArrayD::<f64>::zeros(IxDyn(&[10, 10]));
     ///
     /// assert!(array.into_dimensionality::<Ix2>().is_ok());
+    ///
+    /// let array = ArrayD::<f64>::zeros(IxDyn(&[2, 3, 4]));
+    /// let err = array.into_dimensionality::<Ix2>().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "ShapeError/IncompatibleShape: incompatible shapes (expected 2 axes, got 3)"
+    /// );
     /// ```
     pub fn into_dimensionality<D2>(self) -> Result<ArrayBase<S, D2>, ShapeError>
     where
@@ -1693,7 +2257,8 @@ where
                 }
             }
         }
-        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+        // only conversions to a static dimensionality can fail
+        Err(error::incompatible_ndim(D2::NDIM.unwrap_or(0), self.ndim()))
     }
 
     /// Act like a larger size and/or shape array by *broadcasting*
@@ -1790,6 +2355,39 @@ where
         unsafe { Some(ArrayView::new(self.ptr, dim, broadcast_strides)) }
     }
 
+    /// Broadcast the array into `shape` and return the result as a new array
+    /// in standard layout; see [`.broadcast()`](ArrayBase::broadcast).
+    ///
+    /// Unlike the broadcast view, every element of the result has its own
+    /// storage, so it can be mutated or passed on where zero strides are not
+    /// supported.
+    ///
+    /// ***Errors*** if the array can not be broadcast into `shape`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr1(&[1, 2]).expand_to((2, 2)).unwrap();
+    /// assert_eq!(a, arr2(&[[1, 2],
+    ///                      [1, 2]]));
+    /// assert!(a.is_standard_layout());
+    /// ```
+    pub fn expand_to<E>(&self, shape: E) -> Result<Array<A, E::Dim>, ShapeError>
+    where
+        E: IntoDimension,
+        S: Data,
+        A: Clone,
+    {
+        let shape = shape.into_dimension();
+        let view = match self.broadcast(shape.clone()) {
+            Some(view) => view,
+            None => return Err(error::incompatible_shapes(&self.dim, &shape)),
+        };
+        let mut res = Array::uninit(shape);
+        view.assign_to(&mut res);
+        unsafe { Ok(res.assume_init()) }
+    }
+
     /// For two arrays or views, find their common shape if possible and
     /// broadcast them as array views into that shape.
     ///
@@ -1894,6 +2492,40 @@ where
         }
     }
 
+    /// Return a view of the array for every permutation of its axes, as with
+    /// [`.permuted_axes()`](ArrayBase::permuted_axes).
+    ///
+    /// The views are in lexicographic order of the axis permutation, starting with
+    /// the identity, and have dynamic dimensionality. This is mainly useful for
+    /// testing code that depends on the memory layout of its input.
+    ///
+    /// Note that an array with *n* axes has *n!* permutations, so the number of
+    /// views grows very quickly with the number of axes.
+    ///
+    /// ```
+    /// use ndarray::Array3;
+    ///
+    /// let a = Array3::<f64>::zeros((2, 3, 4));
+    /// let views = a.permute_axes_all();
+    /// assert_eq!(views.len(), 6);
+    /// assert_eq!(views[0].shape(), &[2, 3, 4]);
+    /// assert_eq!(views[5].shape(), &[4, 3, 2]);
+    /// ```
+    pub fn permute_axes_all(&self) -> Vec<ArrayViewD<'_, A>>
+    where
+        S: Data,
+    {
+        let view = self.view().into_dyn();
+        let mut axes: Vec<usize> = (0..self.ndim()).collect();
+        let mut views = Vec::new();
+        loop {
+            views.push(view.clone().permuted_axes(&axes[..]));
+            if !next_permutation(&mut axes) {
+                return views;
+            }
+        }
+    }
+
     /// Transpose the array by reversing axes.
     ///
     /// Transposition reverses the order of the axes (dimensions and strides)
@@ -1948,6 +2580,117 @@ where
         }
     }
 
+    /// Return a view of the array with the order of the elements along `axis` reversed.
+    ///
+    /// The view shares its data with `self`, like NumPy's `flip`; see
+    /// [`.invert_axis()`](ArrayBase::invert_axis).
+    ///
+    /// ***Panics*** if the axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.flip(Axis(0)), arr2(&[[4, 5, 6],
+    ///                                    [1, 2, 3]]));
+    /// assert_eq!(a.flip(Axis(1)), arr2(&[[3, 2, 1],
+    ///                                    [6, 5, 4]]));
+    /// ```
+    pub fn flip(&self, axis: Axis) -> ArrayView<'_, A, D>
+    where
+        S: Data,
+    {
+        self.view().flipped(axis)
+    }
+
+    /// Return a view of the array with the order of the elements along each of `axes`
+    /// reversed.
+    ///
+    /// Each axis is inverted once for each time it occurs in `axes`. The view shares its
+    /// data with `self`.
+    ///
+    /// ***Panics*** if an axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr3, Axis};
+    ///
+    /// let a = arr3(&[[[1, 2], [3, 4]],
+    ///                [[5, 6], [7, 8]]]);
+    /// assert_eq!(a.flip_axes(&[Axis(0), Axis(2)]), arr3(&[[[6, 5], [8, 7]],
+    ///                                                     [[2, 1], [4, 3]]]));
+    /// ```
+    pub fn flip_axes(&self, axes: &[Axis]) -> ArrayView<'_, A, D>
+    where
+        S: Data,
+    {
+        let mut view = self.view();
+        for &axis in axes {
+            view.invert_axis(axis);
+        }
+        view
+    }
+
+    /// Reverse the order of the elements along `axis`, consuming the array or view and
+    /// returning it with the axis inverted; see
+    /// [`.invert_axis()`](ArrayBase::invert_axis).
+    ///
+    /// No elements are moved; only the strides change.
+    ///
+    /// ***Panics*** if the axis is out of bounds.
+    pub fn flipped(mut self, axis: Axis) -> Self {
+        self.invert_axis(axis);
+        self
+    }
+
+    /// Return a copy of the array rotated by 90 degrees `k` times, in the plane of the
+    /// first two axes.
+    ///
+    /// Like NumPy's `rot90`, the rotation is counter-clockwise from the first axis
+    /// towards the second, and a negative `k` rotates clockwise. Only `k` modulo 4
+    /// matters; for odd `k` the lengths of the first two axes are swapped.
+    ///
+    /// ***Panics*** if the array has fewer than two axes.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.rot90(1), arr2(&[[2, 4],
+    ///                               [1, 3]]));
+    /// assert_eq!(a.rot90(-1), arr2(&[[3, 1],
+    ///                                [4, 2]]));
+    /// ```
+    pub fn rot90(&self, k: i32) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        assert!(
+            self.ndim() >= 2,
+            "rot90: array must have at least two axes, got {}",
+            self.ndim()
+        );
+        let mut view = self.view();
+        match k.rem_euclid(4) {
+            1 => {
+                view.invert_axis(Axis(1));
+                view.swap_axes(0, 1);
+            }
+            2 => {
+                view.invert_axis(Axis(0));
+                view.invert_axis(Axis(1));
+            }
+            3 => {
+                view.swap_axes(0, 1);
+                view.invert_axis(Axis(1));
+            }
+            _ => {}
+        }
+        view.to_owned()
+    }
+
     /// If possible, merge in the axis `take` to `into`.
     ///
     /// Returns `true` iff the axes are now merged.
@@ -2031,6 +2774,92 @@ where
         self.index_axis_move(axis, 0)
     }
 
+    /// Check the internal consistency of the array's shape, strides and data
+    /// pointer, when debug assertions are enabled.
+    ///
+    /// This is meant for debugging code that creates or modifies arrays with
+    /// `unsafe`; arrays created through the safe API always pass. It checks that
+    /// the shape and strides have the same number of axes and that the offsets
+    /// they reach don't overflow. For arrays that own their data, it also checks
+    /// that the array does not have more elements than its storage and that every
+    /// element is in bounds of the storage and reached by a single index.
+    /// Views don't know the extent of their data, so only the first checks apply.
+    ///
+    /// Does nothing if debug assertions are disabled.
+    ///
+    /// ***Panics*** with a description of the problem if a check fails.
+    ///
+    /// ```
+    /// use ndarray::{s, Array};
+    ///
+    /// let a = Array::from_shape_vec((3, 4), (0..12).collect::<Vec<i32>>()).unwrap();
+    /// a.debug_assert_invariants();
+    /// a.slice(s![..;-1, 1..]).debug_assert_invariants();
+    /// ```
+    pub fn debug_assert_invariants(&self) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        assert_eq!(
+            self.dim.ndim(),
+            self.strides.ndim(),
+            "ndarray: shape {:?} and strides {:?} have a different number of axes",
+            self.dim.slice(),
+            self.strides()
+        );
+        if let Err(err) = dimension::max_abs_offset_check_overflow::<A, _>(&self.dim, &self.strides) {
+            panic!(
+                "ndarray: strides {:?} are invalid for shape {:?}: {}",
+                self.strides(),
+                self.dim.slice(),
+                err
+            );
+        }
+        let slc = match self.data._data_slice() {
+            Some(slc) => slc,
+            None => return,
+        };
+        assert!(
+            self.len() <= slc.len(),
+            "ndarray: array of {} elements is larger than its storage of {} elements",
+            self.len(),
+            slc.len()
+        );
+        let elem_size = size_of::<A>();
+        if elem_size == 0 {
+            return;
+        }
+        let start = slc.as_ptr() as usize;
+        let end = start + size_of_val(slc);
+        let head = self.ptr.as_ptr() as usize;
+        assert!(
+            head >= start && head <= end,
+            "ndarray: data pointer {:#x} is outside of the storage at {:#x}..{:#x}",
+            head,
+            start,
+            end
+        );
+        // index in the storage of the element with the lowest address
+        let low = ((head - start) / elem_size) as isize
+            + offset_from_ptr_to_memory(&self.dim, &self.strides);
+        let in_bounds = if low < 0 {
+            Err(from_kind(ErrorKind::OutOfBounds))
+        } else {
+            dimension::can_index_slice(&slc[low as usize..], &self.dim, &self.strides)
+        };
+        if let Err(err) = in_bounds {
+            panic!(
+                "ndarray: shape {:?} with strides {:?} at storage index {} is invalid \
+                 for storage of {} elements: {}",
+                self.dim.slice(),
+                self.strides(),
+                (head - start) / elem_size,
+                slc.len(),
+                err
+            );
+        }
+    }
+
     pub(crate) fn pointer_is_inbounds(&self) -> bool {
         match self.data._data_slice() {
             None => {
@@ -2143,6 +2972,19 @@ where
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
     /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let mut a = arr2(&[[1, 2],
+    ///                    [3, 4]]);
+    /// a.zip_mut_with(&arr2(&[[10, 20], [30, 40]]), |x, &y| *x += y);
+    /// assert_eq!(a, arr2(&[[11, 22], [33, 44]]));
+    ///
+    /// // `rhs` is broadcast along the rows of `a`
+    /// a.zip_mut_with(&arr1(&[1, 2]), |x, &y| *x *= y);
+    /// assert_eq!(a, arr2(&[[11, 44], [33, 88]]));
+    /// ```
     #[inline]
     pub fn zip_mut_with<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F)
     where
@@ -2181,6 +3023,84 @@ where
         }
     }
 
+    /// Traverse the array elements and apply a fold that can stop early,
+    /// returning the resulting value.
+    ///
+    /// The fold stops at the first element for which `f` returns `FoldWhile::Done`,
+    /// and the remaining elements are not visited.
+    ///
+    /// Elements are visited in memory order if the array is contiguous, and in
+    /// arbitrary order otherwise.
+    ///
+    /// ```
+    /// use ndarray::{arr1, FoldWhile};
+    ///
+    /// let a = arr1(&[1., 2., f64::NAN, 4.]);
+    ///
+    /// // Sum until a NaN is seen
+    /// let sum = a.fold_while(0., |acc, &x| {
+    ///     if x.is_nan() {
+    ///         FoldWhile::Done(acc)
+    ///     } else {
+    ///         FoldWhile::Continue(acc + x)
+    ///     }
+    /// });
+    /// assert_eq!(sum, 3.);
+    /// ```
+    pub fn fold_while<'a, F, B>(&'a self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a A) -> FoldWhile<B>,
+        A: 'a,
+        S: Data,
+    {
+        let step = move |acc, elt| match f(acc, elt) {
+            FoldWhile::Continue(acc) => Ok(acc),
+            FoldWhile::Done(acc) => Err(acc),
+        };
+        let result = if let Some(slc) = self.as_slice_memory_order() {
+            slc.iter().try_fold(init, step)
+        } else {
+            let mut v = self.view();
+            move_min_stride_axis_to_last(&mut v.dim, &mut v.strides);
+            v.into_elements_base().try_fold(init, step)
+        };
+        match result {
+            Ok(acc) | Err(acc) => acc,
+        }
+    }
+
+    /// Traverse the array elements and apply a fallible fold,
+    /// returning the resulting value or the first error.
+    ///
+    /// The fold stops at the first element for which `f` returns an error,
+    /// and the remaining elements are not visited.
+    ///
+    /// Elements are visited in memory order if the array is contiguous, and in
+    /// arbitrary order otherwise.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&["1", "2", "x", "4"]);
+    /// let sum = a.try_fold(0, |acc, s| s.parse::<i32>().map(|x| acc + x));
+    /// assert!(sum.is_err());
+    ///
+    /// let a = arr1(&["1", "2", "3", "4"]);
+    /// let sum = a.try_fold(0, |acc, s| s.parse::<i32>().map(|x| acc + x));
+    /// assert_eq!(sum, Ok(10));
+    /// ```
+    pub fn try_fold<'a, F, B, E>(&'a self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, &'a A) -> Result<B, E>,
+        A: 'a,
+        S: Data,
+    {
+        self.fold_while(Ok(init), move |acc, elt| match acc.and_then(|acc| f(acc, elt)) {
+            Ok(acc) => FoldWhile::Continue(Ok(acc)),
+            Err(e) => FoldWhile::Done(Err(e)),
+        })
+    }
+
     /// Call `f` by reference on each element and create a new array
     /// with the new values.
     ///
@@ -2269,6 +3189,83 @@ where
         self.map(move |x| f(x.clone()))
     }
 
+    /// Call the fallible `f` by reference on each element and create a new array
+    /// with the new values, or return the first error.
+    ///
+    /// Elements are visited in logical order, and the mapping stops at the first error.
+    ///
+    /// Return an array with the same shape as `self`, in standard layout.
+    pub fn try_map<'a, B, E, F>(&'a self, f: F) -> Result<Array<B, D>, E>
+    where
+        F: FnMut(&'a A) -> Result<B, E>,
+        A: 'a,
+        S: Data,
+    {
+        let v = self.iter().map(f).collect::<Result<Vec<_>, _>>()?;
+        unsafe { Ok(ArrayBase::from_shape_vec_unchecked(self.dim.clone(), v)) }
+    }
+
+    /// Call the fallible `f` by **v**alue on each element and create a new array
+    /// with the new values, or return the first error.
+    ///
+    /// Elements are visited in logical order, and the mapping stops at the first error.
+    ///
+    /// Return an array with the same shape as `self`, in standard layout.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[["1.5", "2"],
+    ///                ["-3", "4e2"]]);
+    /// assert_eq!(
+    ///     a.try_mapv(|s| s.parse::<f64>()),
+    ///     Ok(arr2(&[[1.5, 2.], [-3., 400.]]))
+    /// );
+    /// ```
+    pub fn try_mapv<B, E, F>(&self, mut f: F) -> Result<Array<B, D>, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+        A: Clone,
+        S: Data,
+    {
+        self.try_map(move |x| f(x.clone()))
+    }
+
+    /// Call `f` on each element of `self` together with the corresponding
+    /// element of `other` broadcast to the shape of `self`, and create a new
+    /// array with the results.
+    ///
+    /// `other` is read through a broadcast view, so its elements are not copied
+    /// and no array of the full shape is created except for the result.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// Return an array with the same shape as `self`.
+    ///
+    /// ***Panics*** if `other` can not be broadcast to the shape of `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// let bias = arr1(&[10., 20.]);
+    /// assert_eq!(
+    ///     a.broadcasted_apply(&bias, |x, b| x + b),
+    ///     arr2(&[[11., 22.],
+    ///            [13., 24.]])
+    /// );
+    /// ```
+    pub fn broadcasted_apply<B, C, S2, E, F>(&self, other: &ArrayBase<S2, E>, f: F) -> Array<C, D>
+    where
+        S: Data,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&A, &B) -> C,
+    {
+        Zip::from(self).and_broadcast(other).map_collect(f)
+    }
+
     /// Call `f` by **v**alue on each element, update the array with the new values
     /// and return it.
     ///
@@ -2446,7 +3443,13 @@ where
 
     /// Remove the `index`th elements along `axis` and shift down elements from higher indexes.
     ///
-    /// Note that this "removes" the elements by swapping them around to the end of the axis and
+    /// If the array is an `Array` whose elements are contiguous in memory with `axis` as the
+    /// outermost axis in memory order (for example when removing a row of an array in
+    /// standard layout, or a column of an array in column major layout), the removed elements
+    /// are dropped and the elements after them are moved back in the backing vector. This
+    /// takes time proportional to the number of elements after the removed ones.
+    ///
+    /// Otherwise, this "removes" the elements by swapping them around to the end of the axis and
     /// shortening the length of the axis; the elements are not deinitialized or dropped by this,
     /// just moved out of view (this only matters for elements with ownership semantics). It's
     /// similar to slicing an owned array in place.
@@ -2455,12 +3458,27 @@ where
     ///
     /// ***Panics*** if `axis` is out of bounds<br>
     /// ***Panics*** if not `index < self.len_of(axis)`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6],
+    ///                    [7, 8, 9]];
+    /// a.remove_index(Axis(0), 1);
+    /// assert_eq!(a, array![[1, 2, 3], [7, 8, 9]]);
+    /// a.remove_index(Axis(1), 0);
+    /// assert_eq!(a, array![[2, 3], [8, 9]]);
+    /// ```
     pub fn remove_index(&mut self, axis: Axis, index: usize)
     where
         S: DataOwned + DataMut,
     {
         assert!(index < self.len_of(axis), "index {} must be less than length of Axis({})",
                 index, axis.index());
+        if S::try_remove_index_compact(self, axis, index) {
+            return;
+        }
         let (_, mut tail) = self.view_mut().split_at(axis, index);
         // shift elements to the front
         Zip::from(tail.lanes_mut(axis)).for_each(|mut lane| lane.rotate1_front());
@@ -2541,3 +3559,97 @@ unsafe fn unlimited_transmute<A, B>(data: A) -> B {
 }
 
 type DimMaxOf<A, B> = <A as DimMax<B>>::Output;
+
+/// Return the equivalent right rotation in `0..len` for a circular shift by `shift`.
+fn roll_amount(len: usize, shift: isize) -> usize {
+    // axis lengths fit in isize
+    if len == 0 {
+        0
+    } else {
+        shift.rem_euclid(len as isize) as usize
+    }
+}
+
+fn reverse_lane<A>(lane: &mut ArrayViewMut1<'_, A>) {
+    let len = lane.len();
+    for i in 0..len / 2 {
+        lane.swap(i, len - 1 - i);
+    }
+}
+
+/// Rearrange `xs` into the next permutation in lexicographic order.
+///
+/// Return `false`, leaving `xs` unchanged, if it is already the last permutation.
+fn next_permutation(xs: &mut [usize]) -> bool {
+    let pivot = match (1..xs.len()).rev().find(|&i| xs[i - 1] < xs[i]) {
+        Some(i) => i - 1,
+        None => return false,
+    };
+    let successor = (pivot + 1..xs.len()).rev().find(|&i| xs[i] > xs[pivot]).unwrap();
+    xs.swap(pivot, successor);
+    xs[pivot + 1..].reverse();
+    true
+}
+
+#[cfg(test)]
+#[cfg(debug_assertions)]
+mod tests {
+    use crate::imp_prelude::*;
+    use std::ptr::NonNull;
+
+    // The safe API and the unsafe constructors can't create these arrays in debug
+    // builds, so the tests set the private fields directly.
+
+    fn owned() -> Array2<i32> {
+        Array::from_shape_vec((2, 3), (0..6).collect()).unwrap()
+    }
+
+    #[test]
+    #[should_panic(expected = "is invalid for storage of 6 elements")]
+    fn debug_assert_invariants_out_of_bounds() {
+        let mut a = owned();
+        a.strides = Ix2(3, 2);
+        a.debug_assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "is invalid for storage of 6 elements")]
+    fn debug_assert_invariants_overlap() {
+        let mut a = owned();
+        a.strides = Ix2(1, 1);
+        a.debug_assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "is outside of the storage")]
+    fn debug_assert_invariants_pointer() {
+        let mut a = owned();
+        a.ptr = NonNull::new(a.ptr.as_ptr().wrapping_add(7)).unwrap();
+        a.debug_assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "is larger than its storage of 6 elements")]
+    fn debug_assert_invariants_len() {
+        let mut a = owned();
+        a.dim = Ix2(3, 3);
+        a.debug_assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "have a different number of axes")]
+    fn debug_assert_invariants_ndim() {
+        let mut a = owned().into_dyn();
+        a.strides = IxDyn(&[1]);
+        a.debug_assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "are invalid for shape")]
+    fn debug_assert_invariants_view_overflow() {
+        let a = owned();
+        let mut v = a.view();
+        v.strides = Ix2(isize::MAX as usize, 1);
+        v.debug_assert_invariants();
+    }
+}
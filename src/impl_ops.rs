@@ -338,6 +338,8 @@ mod arithmetic_ops {
     all_scalar_ops!(u64);
     all_scalar_ops!(i128);
     all_scalar_ops!(u128);
+    all_scalar_ops!(isize);
+    all_scalar_ops!(usize);
 
     impl_scalar_lhs_op!(bool, Commute, &, BitAnd, bitand, "bit and");
     impl_scalar_lhs_op!(bool, Commute, |, BitOr, bitor, "bit or");
@@ -395,6 +397,19 @@ mod arithmetic_ops {
         }
     }
 
+    impl<'a, A, D> Neg for ArrayView<'a, A, D>
+    where
+        A: Clone + Neg<Output = A>,
+        D: Dimension,
+    {
+        type Output = Array<A, D>;
+        /// Perform an elementwise negation of the view `self` and return the
+        /// result as a new `Array`.
+        fn neg(self) -> Array<A, D> {
+            self.mapv(Neg::neg)
+        }
+    }
+
     impl<A, S, D> Not for ArrayBase<S, D>
     where
         A: Clone + Not<Output = A>,
@@ -1,6 +1,14 @@
 
+use alloc::vec;
 use alloc::vec::Vec;
+use std::mem::{self, MaybeUninit};
+use std::ptr::{self, NonNull};
+
+use crate::dimension;
+use crate::error::{ErrorKind, ShapeError};
 use crate::imp_prelude::*;
+use crate::low_level_util::AbortIfPanic;
+use crate::Zip;
 
 /// Methods specific to `Array0`.
 ///
@@ -58,4 +66,698 @@ where
     pub fn into_raw_vec(self) -> Vec<A> {
         self.data.into_vec()
     }
+
+    /// Return a vector of the elements in the array, in the way they are
+    /// stored internally, and the index in the vector of the first element of
+    /// the array (the element at the array's logical index zero).
+    ///
+    /// The vector may contain elements that are not part of the array, for example
+    /// if the array was created by slicing an owned array with `.slice_move()`. The
+    /// logical element order still follows the internal storage order, like in
+    /// [`.into_raw_vec()`](#method.into_raw_vec), so use the strides of the array to
+    /// locate the other elements.
+    ///
+    /// The offset is `None` if the array is empty or the element type is zero-sized,
+    /// because it is not meaningful then.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![1, 2, 3, 4, 5].slice_move(s![2..]);
+    /// let (v, offset) = a.into_raw_vec_and_offset();
+    /// assert_eq!(v, vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(offset, Some(2));
+    /// ```
+    pub fn into_raw_vec_and_offset(self) -> (Vec<A>, Option<usize>) {
+        let size = mem::size_of::<A>();
+        let offset = if size == 0 || self.is_empty() {
+            None
+        } else {
+            let first = self.ptr.as_ptr() as usize;
+            let base = self.data.as_ptr() as usize;
+            debug_assert_eq!((first - base) % size, 0);
+            Some((first - base) / size)
+        };
+        (self.data.into_vec(), offset)
+    }
+
+    /// Return the single element of the array without cloning it, if the array has
+    /// exactly one element, whatever its number of dimensions.
+    ///
+    /// ***Errors*** by returning the array unchanged if it does not have exactly one
+    /// element.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Array2};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// let a = a.try_into_scalar().unwrap_err();
+    /// assert_eq!(a.slice_move(s![1.., 1..]).try_into_scalar(), Ok(4));
+    ///
+    /// let b = Array2::<f64>::zeros((1, 0));
+    /// assert!(b.try_into_scalar().is_err());
+    /// ```
+    pub fn try_into_scalar(self) -> Result<A, Self> {
+        if self.len() != 1 {
+            return Err(self);
+        }
+        // The offset is `None` only for zero-sized types, where any index is fine.
+        let (mut v, offset) = self.into_raw_vec_and_offset();
+        Ok(v.swap_remove(offset.unwrap_or(0)))
+    }
+
+    /// Insert an array at the start of `axis`, shifting the existing elements along
+    /// `axis` forward.
+    ///
+    /// The memory of the array is reused when its elements are contiguous in memory
+    /// with `axis` as the outermost axis in memory order, for example axis 0 of an array
+    /// in standard layout or the last axis of an array in column major layout. Axes of
+    /// length one can be in any place in memory order. Otherwise, for example when an
+    /// axis is inverted or the array is sliced, the elements are moved to a new layout
+    /// with `axis` outermost and the other axes in their current memory order, and the
+    /// elements of the backing vector that are not part of the array are dropped. An
+    /// empty array gets a new layout with `axis` outermost and the other axes in
+    /// standard order.
+    ///
+    /// All existing elements are moved to make room for the new ones, so each call takes
+    /// time proportional to the size of the array. The new elements are cloned before
+    /// anything is moved, so if a clone panics, the array is left unchanged.
+    ///
+    /// ***Errors*** with a shape error if the shape of `array` does not match the shape
+    /// of `self` apart from along `axis`.<br>
+    /// ***Errors*** if `axis` is out of bounds or if the result would be too large.
+    ///
+    /// ```
+    /// use ndarray::{Array, ArrayView, Axis, array};
+    ///
+    /// let mut a = Array::zeros((0, 2, 2));
+    /// a.try_prepend_array(Axis(0), array![[[1, 2], [3, 4]]].view()).unwrap();
+    /// a.try_prepend_array(Axis(0), array![[[5, 6], [7, 8]]].view()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     array![[[5, 6], [7, 8]],
+    ///            [[1, 2], [3, 4]]]);
+    /// ```
+    pub fn try_prepend_array(&mut self, axis: Axis, array: ArrayView<A, D>)
+        -> Result<(), ShapeError>
+    where
+        A: Clone,
+    {
+        let ndim = self.ndim();
+        if array.ndim() != ndim {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+        }
+        if axis.index() >= ndim {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds));
+        }
+        let other_axes_match = self
+            .shape()
+            .iter()
+            .zip(array.shape())
+            .enumerate()
+            .all(|(i, (a, b))| i == axis.index() || a == b);
+        if !other_axes_match {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+        }
+
+        let mut res_dim = self.raw_dim();
+        res_dim[axis.index()] += array.len_of(axis);
+        dimension::size_of_shape_checked(&res_dim)?;
+
+        let (memory_order, contiguous) = match self.outermost_memory_order(axis) {
+            Some(order) => (order, true),
+            None => (self.memory_order_with_outermost(axis), false),
+        };
+
+        // Clone the new elements first, so that a panic in clone leaves `self` untouched.
+        let mut new_elements = array
+            .permuted_axes(memory_order.clone())
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut v = if contiguous {
+            self.take_reachable_vec()
+        } else {
+            self.take_vec_in_memory_order(&memory_order)
+        };
+        // Move the existing elements forward and write the new ones in front of them
+        v.splice(0..0, new_elements.drain(..));
+        *self = Self::from_vec_in_memory_order(res_dim, &memory_order, v);
+        Ok(())
+    }
+
+    /// Keep only the subviews along `axis` for which `f` returns `true`, and remove the
+    /// others, like `Vec::retain`.
+    ///
+    /// `f` is called once for each subview along `axis`, in order, before any element is
+    /// moved. The kept subviews are then moved toward the front of the backing vector in a
+    /// single pass, preserving their order, and the elements of the removed subviews are
+    /// dropped.
+    ///
+    /// The array must be empty, or have its elements contiguous in memory with `axis` as
+    /// the outermost axis in memory order, for example axis 0 of an array in standard
+    /// layout or the last axis of an array in column major layout.
+    ///
+    /// ***Errors*** with a layout error if the array is not empty and not in a layout as
+    /// described above. The array is left unchanged then, and `f` is not called.<br>
+    /// ***Errors*** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1., 2.],
+    ///                    [-1., 3.],
+    ///                    [4., 5.],
+    ///                    [6., -2.]];
+    /// // keep the rows without negative elements
+    /// a.retain_axis(Axis(0), |row| row.iter().all(|&x| x >= 0.)).unwrap();
+    /// assert_eq!(a, array![[1., 2.], [4., 5.]]);
+    /// ```
+    pub fn retain_axis<F>(&mut self, axis: Axis, mut f: F) -> Result<(), ShapeError>
+    where
+        F: FnMut(ArrayView<A, D::Smaller>) -> bool,
+        D: RemoveAxis,
+    {
+        if axis.index() >= self.ndim() {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds));
+        }
+        let memory_order = match self.outermost_memory_order(axis) {
+            Some(order) => order,
+            None => return Err(ShapeError::from_kind(ErrorKind::IncompatibleLayout)),
+        };
+        let keep = self.axis_iter(axis).map(&mut f).collect::<Vec<_>>();
+        let mut res_dim = self.raw_dim();
+        res_dim[axis.index()] = keep.iter().filter(|&&k| k).count();
+
+        let lane_size = if keep.is_empty() { 0 } else { self.len() / keep.len() };
+        let mut v = self.take_reachable_vec();
+        let mut i = 0;
+        v.retain(|_| {
+            let k = keep[i / lane_size];
+            i += 1;
+            k
+        });
+        *self = Self::from_vec_in_memory_order(res_dim, &memory_order, v);
+        Ok(())
+    }
+
+    /// Remove the last subview along `axis` and return it as an owned array, or return
+    /// `None` if the length of `axis` is zero.
+    ///
+    /// This is the inverse of appending along `axis`: the elements of the subview are
+    /// moved out of the end of the backing vector, so the remaining elements are not
+    /// moved or copied.
+    ///
+    /// The array must have its elements contiguous in memory with `axis` as the
+    /// outermost axis in memory order, for example axis 0 of an array in standard
+    /// layout or the last axis of an array in column major layout. The returned array
+    /// keeps the memory order of the other axes.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the length of `axis` is not zero and
+    /// the array is not in a layout as described above.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// assert_eq!(a.pop_along(Axis(0)), Some(array![4, 5, 6]));
+    /// assert_eq!(a.pop_along(Axis(0)), Some(array![1, 2, 3]));
+    /// assert_eq!(a.pop_along(Axis(0)), None);
+    /// assert_eq!(a.shape(), &[0, 3]);
+    /// ```
+    pub fn pop_along(&mut self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        D: RemoveAxis,
+    {
+        let axis_len = self.len_of(axis);
+        if axis_len == 0 {
+            return None;
+        }
+        let memory_order = match self.outermost_memory_order(axis) {
+            Some(order) => order,
+            None => panic!(
+                "ndarray: pop_along requires a contiguous array with axis {} outermost in memory",
+                axis.index()
+            ),
+        };
+        let mut res_dim = self.raw_dim();
+        res_dim[axis.index()] -= 1;
+        let mut lane_dim = self.raw_dim();
+        lane_dim[axis.index()] = 1;
+        let len = self.len();
+        let lane_size = len / axis_len;
+
+        // Split the last lane off the end of the backing vector, and keep the remaining
+        // elements where they are.
+        let offset = self.offset_in_vec();
+        let ptr = self.ptr;
+        let strides = self.strides.clone();
+        let mut v = self.take_vec();
+        v.truncate(offset + len);
+        let lane = v.split_off(offset + len - lane_size);
+        *self = if len == 0 {
+            Self::from_vec_in_memory_order(res_dim, &memory_order, v)
+        } else {
+            unsafe {
+                ArrayBase::from_data_ptr(DataOwned::new(v), ptr).with_strides_dim(strides, res_dim)
+            }
+        };
+        Some(Self::from_vec_in_memory_order(lane_dim, &memory_order, lane).index_axis_move(axis, 0))
+    }
+
+    /// Reserve capacity in the backing vector for at least `additional` more subviews
+    /// along `axis`, so that growing the array along `axis` by that much does not need to
+    /// reallocate.
+    ///
+    /// The number of elements reserved is `additional` times the size of a subview along
+    /// `axis`. The array itself is not changed, and nothing is done if `additional` is
+    /// zero.
+    ///
+    /// The array must be empty, or have its elements contiguous in memory with `axis` as
+    /// the outermost axis in memory order, for example axis 0 of an array in standard
+    /// layout or the last axis of an array in column major layout, since the array can
+    /// only grow in place along such an axis.
+    ///
+    /// ***Errors*** with a layout error if the array is not empty and not in a layout as
+    /// described above.<br>
+    /// ***Errors*** if `axis` is out of bounds or if the grown array would be too large.
+    ///
+    /// ```
+    /// use ndarray::{Array, ArrayView, Axis};
+    ///
+    /// let mut a = Array::<f64, _>::zeros((0, 4));
+    /// a.reserve(Axis(0), 100).unwrap();
+    /// for i in 0..100 {
+    ///     a.try_prepend_row(ArrayView::from(&[i as f64; 4])).unwrap();
+    /// }
+    /// assert_eq!(a.shape(), &[100, 4]);
+    /// ```
+    pub fn reserve(&mut self, axis: Axis, additional: usize) -> Result<(), ShapeError> {
+        self.reserve_impl(axis, additional, false)
+    }
+
+    /// Reserve capacity in the backing vector for exactly `additional` more subviews along
+    /// `axis`, like [`.reserve()`](#method.reserve) but without over-allocating, like
+    /// `Vec::reserve_exact`.
+    ///
+    /// ***Errors*** with a layout error if the array is not empty and not in a layout as
+    /// described in [`.reserve()`](#method.reserve).<br>
+    /// ***Errors*** if `axis` is out of bounds or if the grown array would be too large.
+    pub fn reserve_exact(&mut self, axis: Axis, additional: usize) -> Result<(), ShapeError> {
+        self.reserve_impl(axis, additional, true)
+    }
+
+    fn reserve_impl(&mut self, axis: Axis, additional: usize, exact: bool)
+        -> Result<(), ShapeError>
+    {
+        if axis.index() >= self.ndim() {
+            return Err(ShapeError::from_kind(ErrorKind::OutOfBounds));
+        }
+        if additional == 0 {
+            return Ok(());
+        }
+        let mut grown_dim = self.raw_dim();
+        grown_dim[axis.index()] = self
+            .len_of(axis)
+            .checked_add(additional)
+            .ok_or_else(|| ShapeError::from_kind(ErrorKind::Overflow))?;
+        dimension::size_of_shape_checked(&grown_dim)?;
+        if self.outermost_memory_order(axis).is_none() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleLayout));
+        }
+
+        let mut extra_dim = self.raw_dim();
+        extra_dim[axis.index()] = additional;
+        let extra = extra_dim.size();
+        // The capacity of a `Vec` in bytes must not exceed `isize::MAX`.
+        let too_large = self
+            .data
+            .len()
+            .checked_add(extra)
+            .and_then(|n| n.checked_mul(mem::size_of::<A>()))
+            .filter(|&bytes| bytes <= isize::MAX as usize)
+            .is_none();
+        if too_large {
+            return Err(ShapeError::from_kind(ErrorKind::Overflow));
+        }
+
+        let offset = if self.is_empty() || mem::size_of::<A>() == 0 {
+            0
+        } else {
+            (self.ptr.as_ptr() as usize - self.data.as_ptr() as usize) / mem::size_of::<A>()
+        };
+        let data_ptr = if exact {
+            self.data.reserve_exact(extra)
+        } else {
+            self.data.reserve(extra)
+        };
+        // The allocation may have moved, so point at the first element again.
+        self.ptr = unsafe { NonNull::new_unchecked(data_ptr.as_ptr().add(offset)) };
+        Ok(())
+    }
+
+    /// Compact the backing vector to exactly the elements of the array, in standard
+    /// layout, and release its spare capacity.
+    ///
+    /// Elements of the backing vector that are not part of the array, for example after
+    /// slicing with `.slice_collapse()`, are dropped. The elements of the array are moved
+    /// in logical order, not cloned, so the array is in standard layout afterwards. If the
+    /// array already is in standard layout without such elements, only the spare
+    /// capacity is released.
+    ///
+    /// Moving the elements can't panic. If dropping one of the removed elements panics,
+    /// the array is already valid and in its new layout, and the removed elements that
+    /// were not dropped yet are leaked.
+    ///
+    /// ```
+    /// use ndarray::{Array, s};
+    ///
+    /// let mut a = Array::from_shape_vec((4, 6), (0..24).collect()).unwrap();
+    /// a.slice_collapse(s![1..3, ..;2]);
+    /// a.shrink_to_fit();
+    /// assert!(a.is_standard_layout());
+    /// assert_eq!(a.into_raw_vec(), vec![6, 8, 10, 12, 14, 16]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let len = self.len();
+        if self.is_standard_layout() && self.data.len() == len {
+            // The elements fill the backing vector in order
+            self.ptr = self.data.shrink_to_fit();
+            return;
+        }
+
+        let mut v = Vec::with_capacity(len);
+        for elt in self.iter() {
+            // The elements are moved: the old vector no longer owns them after this loop.
+            unsafe {
+                v.push(ptr::read(elt));
+            }
+        }
+        let dim = self.raw_dim();
+        let new_array = unsafe { Array::from_shape_vec_unchecked(dim, v) };
+        unsafe {
+            mem::replace(self, new_array).drop_unreachable_elements();
+        }
+    }
+
+    /// Move all elements from self into `new_array`, which must be of the same shape but
+    /// can have a different memory layout. The destination is overwritten completely.
+    ///
+    /// The destination should be a mut reference to an array or an `ArrayViewMut` with
+    /// `MaybeUninit<A>` elements (which are overwritten without dropping any existing value).
+    ///
+    /// Minor implementation note: Owned arrays like `self` may be sliced in place and own elements
+    /// that are not part of their active view; these are dropped at the end of this function,
+    /// after all elements in the "active view" are moved into `new_array`. If there is a panic in
+    /// drop of any such element, other elements may be leaked.
+    ///
+    /// ***Panics*** if the shapes don't agree.
+    ///
+    /// ```
+    /// use ndarray::{Array, ShapeBuilder, array};
+    ///
+    /// let a = array![[String::from("a"), String::from("b")],
+    ///                [String::from("c"), String::from("d")]];
+    /// let mut b = Array::uninit((2, 2).f());
+    /// a.move_into(&mut b);
+    /// let b = unsafe { b.assume_init() };
+    /// assert_eq!(b[[1, 0]], "c");
+    /// assert!(!b.is_standard_layout());
+    /// ```
+    pub fn move_into<'a, AM>(self, new_array: AM)
+    where
+        AM: Into<ArrayViewMut<'a, MaybeUninit<A>, D>>,
+        A: 'a,
+    {
+        // Remove generic parameter AM and call the implementation
+        self.move_into_impl(new_array.into())
+    }
+
+    fn move_into_impl(mut self, new_array: ArrayViewMut<MaybeUninit<A>, D>) {
+        // Check the shapes before any element is moved
+        let zip = Zip::from(self.raw_view_mut()).and(new_array);
+        unsafe {
+            // Safety: copy_to_nonoverlapping cannot panic
+            let guard = AbortIfPanic(&"move_into: moving out of owned value");
+            // Move all reachable elements
+            zip.for_each(|src, dst| {
+                src.copy_to_nonoverlapping(dst.as_mut_ptr(), 1);
+            });
+            guard.defuse();
+            // Drop all unreachable elements
+            self.drop_unreachable_elements();
+        }
+    }
+
+    /// Drop the elements of the backing vector that are not part of the array, and free
+    /// the backing vector.
+    ///
+    /// If dropping one of these elements panics, the remaining ones are leaked.
+    ///
+    /// ## Safety
+    ///
+    /// The elements of the array must have been moved out already; they are not dropped.
+    pub(crate) unsafe fn drop_unreachable_elements(self) {
+        let len = self.len();
+        let data_len = self.data.len();
+        // Fast path: either every element was moved out or there is nothing to drop
+        if len == data_len || !mem::needs_drop::<A>() {
+            let mut v = self.into_raw_vec();
+            v.set_len(0);
+            return;
+        }
+
+        if mem::size_of::<A>() == 0 {
+            // Zero-sized elements have no addresses to tell them apart; drop as many as
+            // were not moved out.
+            let mut v = self.into_raw_vec();
+            v.set_len(0);
+            let v_ptr = v.as_mut_ptr();
+            for i in len..data_len {
+                ptr::drop_in_place(v_ptr.add(i));
+            }
+            return;
+        }
+
+        // Which elements of the backing vector belong to the array
+        let mut reachable = vec![false; data_len];
+        let base = self.data.as_ptr();
+        Zip::from(self.raw_view()).for_each(|elt| {
+            reachable[elt.offset_from(base) as usize] = true;
+        });
+
+        let mut v = self.into_raw_vec();
+        v.set_len(0);
+        let v_ptr = v.as_mut_ptr();
+        for (i, &is_reachable) in reachable.iter().enumerate() {
+            if !is_reachable {
+                ptr::drop_in_place(v_ptr.add(i));
+            }
+        }
+    }
+
+    /// Remove the lane at `index` along `axis` by dropping its elements and moving the
+    /// following elements back in the backing vector.
+    ///
+    /// Return `false`, without changing the array, if the elements of the array are not
+    /// contiguous in memory with `axis` as the outermost axis in memory order.
+    pub(crate) fn remove_index_compact(&mut self, axis: Axis, index: usize) -> bool {
+        let memory_order = match self.outermost_memory_order(axis) {
+            Some(order) => order,
+            None => return false,
+        };
+        let axis_len = self.len_of(axis);
+        debug_assert!(index < axis_len);
+        let mut res_dim = self.raw_dim();
+        res_dim[axis.index()] -= 1;
+        let lane_size = self.len() / axis_len;
+        let mut v = self.take_reachable_vec();
+        v.drain(index * lane_size..(index + 1) * lane_size);
+        *self = Self::from_vec_in_memory_order(res_dim, &memory_order, v);
+        true
+    }
+
+    /// Return the axes in memory order, outermost first, with `axis` first, if the
+    /// elements of the array are contiguous in that order.
+    ///
+    /// For an empty array, return `axis` followed by the other axes in standard order.
+    fn outermost_memory_order(&self, axis: Axis) -> Option<D> {
+        let memory_order = self.memory_order_with_outermost(axis);
+        if self.is_empty() || self.view().permuted_axes(memory_order.clone()).is_standard_layout() {
+            Some(memory_order)
+        } else {
+            None
+        }
+    }
+
+    /// Return `axis` followed by the other axes in memory order, outermost first.
+    ///
+    /// For an empty array, the other axes are in standard order.
+    fn memory_order_with_outermost(&self, axis: Axis) -> D {
+        let ndim = self.ndim();
+        let mut memory_order = self.raw_dim();
+        memory_order[0] = axis.index();
+        let other_axes = if self.is_empty() {
+            (0..ndim).collect::<Vec<_>>()
+        } else {
+            let mut order = self.strides._fastest_varying_stride_order().slice().to_vec();
+            order.reverse();
+            order
+        };
+        for (i, &ax) in other_axes.iter().filter(|&&ax| ax != axis.index()).enumerate() {
+            memory_order[i + 1] = ax;
+        }
+        memory_order
+    }
+
+    /// Take the backing vector out of the array, leaving an empty array, and remove
+    /// the elements that are not part of the array from it.
+    ///
+    /// The elements of the array must be contiguous in memory.
+    fn take_reachable_vec(&mut self) -> Vec<A> {
+        let len = self.len();
+        let offset = self.offset_in_vec();
+        let mut v = self.take_vec();
+        v.truncate(offset + len);
+        v.drain(..offset);
+        v
+    }
+
+    /// Take the backing vector out of the array, leaving an empty array.
+    fn take_vec(&mut self) -> Vec<A> {
+        let placeholder =
+            unsafe { Array::from_shape_vec_unchecked(D::zeros(self.ndim()), Vec::new()) };
+        mem::replace(self, placeholder).into_raw_vec()
+    }
+
+    /// Return the index in the backing vector of the element the data pointer points to.
+    fn offset_in_vec(&self) -> usize {
+        if self.is_empty() || mem::size_of::<A>() == 0 {
+            0
+        } else {
+            (self.ptr.as_ptr() as usize - self.data.as_ptr() as usize) / mem::size_of::<A>()
+        }
+    }
+
+    /// Take the elements of the array out, in standard order of the axes permuted by
+    /// `memory_order`, leaving an empty array, and drop the elements of the backing vector
+    /// that are not part of the array.
+    fn take_vec_in_memory_order(&mut self, memory_order: &D) -> Vec<A> {
+        let mut v = Vec::with_capacity(self.len());
+        for elt in self.view().permuted_axes(memory_order.clone()).iter() {
+            // The elements are moved: the old vector no longer owns them after this loop.
+            unsafe {
+                v.push(ptr::read(elt));
+            }
+        }
+        let placeholder =
+            unsafe { Array::from_shape_vec_unchecked(D::zeros(self.ndim()), Vec::new()) };
+        unsafe {
+            mem::replace(self, placeholder).drop_unreachable_elements();
+        }
+        v
+    }
+
+    /// Create an array of shape `dim` from the elements of `v` in standard order of the
+    /// axes permuted by `memory_order`.
+    fn from_vec_in_memory_order(dim: D, memory_order: &D, v: Vec<A>) -> Self {
+        let mut memory_dim = dim.clone();
+        let mut inverse_order = memory_order.clone();
+        for (i, &ax) in memory_order.slice().iter().enumerate() {
+            memory_dim[i] = dim[ax];
+            inverse_order[ax] = i;
+        }
+        let res = unsafe { Array::from_shape_vec_unchecked(memory_dim, v) };
+        res.permuted_axes(inverse_order)
+    }
+}
+
+/// Methods specific to `Array2`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+///
+/// [`ArrayBase`]: struct.ArrayBase.html
+impl<A> Array<A, Ix2> {
+    /// Insert a row at the start of the array, shifting the existing rows down.
+    ///
+    /// If the array is not in standard (row major) layout, its elements are moved to
+    /// standard layout first. All existing elements are moved to make room for the new
+    /// row, so each call takes time proportional to the size of the array.
+    ///
+    /// ***Errors*** with a shape error if the length of the row does not match the number
+    /// of columns in the array.
+    ///
+    /// ```
+    /// use ndarray::{Array, ArrayView, array};
+    ///
+    /// // create an empty array and prepend
+    /// let mut a = Array::zeros((0, 4));
+    /// a.try_prepend_row(ArrayView::from(&[1., 2., 3., 4.])).unwrap();
+    /// a.try_prepend_row(ArrayView::from(&[-1., -2., -3., -4.])).unwrap();
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     array![[-1., -2., -3., -4.],
+    ///            [ 1.,  2.,  3.,  4.]]);
+    /// ```
+    pub fn try_prepend_row(&mut self, row: ArrayView<A, Ix1>) -> Result<(), ShapeError>
+    where
+        A: Clone,
+    {
+        self.try_prepend_array(Axis(0), row.insert_axis(Axis(0)))
+    }
+
+    /// Insert a column at the start of the array, shifting the existing columns right.
+    ///
+    /// If the array is not in column major ("F") layout, its elements are moved to column
+    /// major layout first. All existing elements are moved to make room for the new
+    /// column, so each call takes time proportional to the size of the array.
+    ///
+    /// ***Errors*** with a shape error if the length of the column does not match the number
+    /// of rows in the array.
+    ///
+    /// ```
+    /// use ndarray::{Array, ArrayView, array};
+    ///
+    /// // create an empty array and prepend
+    /// let mut a = Array::zeros((2, 0));
+    /// a.try_prepend_column(ArrayView::from(&[1., 2.])).unwrap();
+    /// a.try_prepend_column(ArrayView::from(&[-1., -2.])).unwrap();
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     array![[-1., 1.],
+    ///            [-2., 2.]]);
+    /// ```
+    pub fn try_prepend_column(&mut self, column: ArrayView<A, Ix1>) -> Result<(), ShapeError>
+    where
+        A: Clone,
+    {
+        self.try_prepend_array(Axis(1), column.insert_axis(Axis(1)))
+    }
+
+    /// Remove the last row of the array and return it, or return `None` if the array has
+    /// no rows.
+    ///
+    /// This lets the array be used as a stack of rows. The remaining elements are not
+    /// moved.
+    ///
+    /// **Panics** if the array has rows and is not in standard (row major) layout.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1., 2.],
+    ///                    [3., 4.]];
+    /// assert_eq!(a.pop_row(), Some(array![3., 4.]));
+    /// assert_eq!(a, array![[1., 2.]]);
+    /// assert_eq!(a.pop_row(), Some(array![1., 2.]));
+    /// assert_eq!(a.pop_row(), None);
+    /// ```
+    pub fn pop_row(&mut self) -> Option<Array<A, Ix1>> {
+        self.pop_along(Axis(0))
+    }
 }
@@ -0,0 +1,143 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::Slice;
+
+/// How [`.pad()`](ArrayBase::pad) fills the padding.
+///
+/// The examples show the padding of `[1, 2, 3]` with two elements on each side.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PadMode<A> {
+    /// Pad with a constant value: `[0, 0, 1, 2, 3, 0, 0]` for `Constant(0)`.
+    Constant(A),
+    /// Pad with the edge elements: `[1, 1, 1, 2, 3, 3, 3]`.
+    Edge,
+    /// Pad with the reflection of the elements, mirrored at the edge elements, which
+    /// are not repeated: `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+    /// Pad with the elements from the other end, as if the array repeats periodically:
+    /// `[2, 3, 1, 2, 3, 1, 2]`.
+    Wrap,
+}
+
+/// # Padding
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    /// Return a copy of the array with padding added before and after the elements
+    /// along each axis.
+    ///
+    /// `pad_width[i]` is the number of elements to add before and after the elements
+    /// along axis `i`, so the result has length
+    /// `len_of(Axis(i)) + pad_width[i].0 + pad_width[i].1` along that axis. `mode`
+    /// determines the values of the padding; see [`PadMode`].
+    ///
+    /// The padding is applied one axis after the other, so for arrays of two or more
+    /// dimensions the corners are filled by padding the padding. With
+    /// [`PadMode::Reflect`], for example, the corners are reflected along both axes.
+    /// Padding that is wider than the axis repeats the pattern of the mode.
+    ///
+    /// **Panics** if `pad_width` does not have one element per axis, if the padded length
+    /// of an axis overflows `usize`, or if the mode is not [`PadMode::Constant`] and an
+    /// axis with padding has length zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, PadMode};
+    ///
+    /// let a = arr1(&[1, 2, 3]);
+    /// assert_eq!(a.pad(&[(2, 1)], PadMode::Constant(0)), arr1(&[0, 0, 1, 2, 3, 0]));
+    /// assert_eq!(a.pad(&[(2, 1)], PadMode::Edge), arr1(&[1, 1, 1, 2, 3, 3]));
+    /// assert_eq!(a.pad(&[(2, 1)], PadMode::Reflect), arr1(&[3, 2, 1, 2, 3, 2]));
+    /// assert_eq!(a.pad(&[(2, 1)], PadMode::Wrap), arr1(&[2, 3, 1, 2, 3, 1]));
+    ///
+    /// let b = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(
+    ///     b.pad(&[(1, 0), (1, 1)], PadMode::Reflect),
+    ///     arr2(&[[4, 3, 4, 3],
+    ///            [2, 1, 2, 1],
+    ///            [4, 3, 4, 3]])
+    /// );
+    /// ```
+    pub fn pad(&self, pad_width: &[(usize, usize)], mode: PadMode<A>) -> Array<A, D>
+    where
+        A: Clone,
+    {
+        assert_eq!(
+            pad_width.len(),
+            self.ndim(),
+            "ndarray: pad requires one pad width per axis, got {} for {} axes",
+            pad_width.len(),
+            self.ndim()
+        );
+        let mut dim = self.raw_dim();
+        for (len, &(before, after)) in dim.slice_mut().iter_mut().zip(pad_width) {
+            *len = len
+                .checked_add(before)
+                .and_then(|len| len.checked_add(after))
+                .expect("ndarray: pad width overflows usize");
+        }
+
+        if let PadMode::Constant(value) = mode {
+            let mut res = Array::from_elem(dim, value);
+            res.slice_each_axis_mut(|ax| {
+                let before = pad_width[ax.axis.index()].0;
+                Slice::from(before..before + self.len_of(ax.axis))
+            })
+            .assign(self);
+            return res;
+        }
+
+        // Gather along one axis at a time; the source index of each position along an
+        // axis only depends on the position along that axis.
+        let mut res = self.to_owned();
+        for (axis, &(before, after)) in pad_width.iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+            let len = self.len_of(Axis(axis));
+            assert!(
+                len > 0,
+                "ndarray: pad requires nonzero axis lengths for modes other than Constant, \
+                 axis {} is empty",
+                axis
+            );
+            let indices: Vec<usize> = (0..before + len + after)
+                .map(|i| pad_source_index(&mode, i as isize - before as isize, len))
+                .collect();
+            res = res.select(Axis(axis), &indices);
+        }
+        res
+    }
+}
+
+/// Return the index in `0..len` that fills position `i` of an axis of length `len`
+/// (`len > 0`), where positions outside of `0..len` are padding.
+fn pad_source_index<A>(mode: &PadMode<A>, i: isize, len: usize) -> usize {
+    let len = len as isize;
+    let index = match mode {
+        PadMode::Constant(_) | PadMode::Edge => i.max(0).min(len - 1),
+        PadMode::Reflect if len == 1 => 0,
+        PadMode::Reflect => {
+            let period = 2 * (len - 1);
+            let j = i.rem_euclid(period);
+            if j < len {
+                j
+            } else {
+                period - j
+            }
+        }
+        PadMode::Wrap => i.rem_euclid(len),
+    };
+    index as usize
+}
@@ -0,0 +1,252 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Sorting Methods
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    /// Sort each lane along `axis` in ascending order, in place.
+    ///
+    /// The sort is stable. For floating point elements, use
+    /// [`.sort_axis_by()`](ArrayBase::sort_axis_by) with a comparison such as
+    /// `|a, b| a.partial_cmp(b).unwrap()`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[3, 1, 2],
+    ///                    [0, 5, 4]]);
+    /// a.sort_axis(Axis(1));
+    /// assert_eq!(a, arr2(&[[1, 2, 3], [0, 4, 5]]));
+    /// a.sort_axis(Axis(0));
+    /// assert_eq!(a, arr2(&[[0, 2, 3], [1, 4, 5]]));
+    /// ```
+    pub fn sort_axis(&mut self, axis: Axis)
+    where
+        A: Ord,
+    {
+        self.sort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Sort each lane along `axis` with the comparison function `compare`, in place.
+    ///
+    /// The sort is stable.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut perm = Vec::new();
+        for mut lane in self.lanes_mut(axis) {
+            match lane.as_slice_mut() {
+                Some(slc) => slc.sort_by(&mut compare),
+                None => sort_lane_by(&mut lane, &mut perm, &mut compare),
+            }
+        }
+    }
+
+    /// Sort each lane along `axis` by the key extraction function `f`, in place.
+    ///
+    /// The sort is stable.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_axis_by_key<K, F>(&mut self, axis: Axis, mut f: F)
+    where
+        F: FnMut(&A) -> K,
+        K: Ord,
+    {
+        self.sort_axis_by(axis, move |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Sort each lane along `axis` in ascending order, in place, without preserving the
+    /// order of equal elements.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_axis_unstable(&mut self, axis: Axis)
+    where
+        A: Ord,
+    {
+        self.sort_axis_unstable_by(axis, Ord::cmp)
+    }
+
+    /// Sort each lane along `axis` with the comparison function `compare`, in place,
+    /// without preserving the order of equal elements.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_axis_unstable_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut perm = Vec::new();
+        for mut lane in self.lanes_mut(axis) {
+            match lane.as_slice_mut() {
+                Some(slc) => slc.sort_unstable_by(&mut compare),
+                None => sort_lane_by(&mut lane, &mut perm, &mut compare),
+            }
+        }
+    }
+}
+
+/// Stable sort of a (non-contiguous) lane, using `perm` as scratch space.
+///
+/// The sorting permutation is computed first and then applied to the lane by swapping
+/// elements along its cycles, so the elements don't need to be `Clone`.
+fn sort_lane_by<A, F>(lane: &mut ArrayViewMut1<'_, A>, perm: &mut Vec<usize>, compare: &mut F)
+where
+    F: FnMut(&A, &A) -> Ordering,
+{
+    perm.clear();
+    perm.extend(0..lane.len());
+    perm.sort_by(|&i, &j| compare(&lane[i], &lane[j]));
+    // Place the element from position perm[i] at position i; done positions are
+    // marked with perm[i] == i.
+    for start in 0..perm.len() {
+        let mut cur = start;
+        while perm[cur] != start {
+            let next = perm[cur];
+            lane.swap(cur, next);
+            perm[cur] = cur;
+            cur = next;
+        }
+        perm[cur] = cur;
+    }
+}
+
+/// # Sorting Methods
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return whether each lane along `axis` is sorted in non-decreasing order.
+    ///
+    /// A lane is sorted if `a <= b` holds for each pair of consecutive elements, so a
+    /// lane where a NaN is next to any other element is not sorted. Lanes with at most
+    /// one element are sorted.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 2],
+    ///                [3, 1, 4]]);
+    /// assert_eq!(a.is_sorted_axis(Axis(1)), arr1(&[true, false]));
+    /// assert_eq!(a.is_sorted_axis(Axis(0)), arr1(&[true, false, true]));
+    /// ```
+    pub fn is_sorted_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| is_sorted_lane(&lane))
+    }
+
+    /// Return the indices that sort each lane along `axis` in ascending order.
+    ///
+    /// The result has the same shape as `self`; each of its lanes along `axis` holds the
+    /// positions of the elements of the corresponding lane of `self`, in sorted order.
+    /// The sort is stable, so equal elements keep their relative order. Use
+    /// [`.take_along_axis()`](ArrayBase::take_along_axis) to reorder this or another
+    /// array with the result.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[3, 1, 2],
+    ///                [0, 5, 0]]);
+    /// let perm = a.argsort_axis(Axis(1));
+    /// assert_eq!(perm, arr2(&[[1, 2, 0],
+    ///                         [0, 2, 1]]));
+    /// assert_eq!(a.take_along_axis(&perm, Axis(1)), arr2(&[[1, 2, 3],
+    ///                                                     [0, 0, 5]]));
+    /// ```
+    pub fn argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where
+        A: Ord,
+    {
+        self.argsort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Return the indices that sort each lane along `axis` with the comparison function
+    /// `compare`.
+    ///
+    /// The sort is stable. See [`.argsort_axis()`](ArrayBase::argsort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn argsort_axis_by<F>(&self, axis: Axis, mut compare: F) -> Array<usize, D>
+    where
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut res = Array::zeros(self.raw_dim());
+        let mut perm = Vec::new();
+        Zip::from(res.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut res_lane, lane| {
+                perm.clear();
+                perm.extend(0..lane.len());
+                perm.sort_by(|&i, &j| compare(&lane[i], &lane[j]));
+                res_lane.assign(&ArrayView1::from(&perm[..]));
+            });
+        res
+    }
+
+    /// Return the indices that sort each lane along `axis` by the key extraction function
+    /// `f`.
+    ///
+    /// The sort is stable. See [`.argsort_axis()`](ArrayBase::argsort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn argsort_axis_by_key<K, F>(&self, axis: Axis, mut f: F) -> Array<usize, D>
+    where
+        F: FnMut(&A) -> K,
+        K: Ord,
+    {
+        self.argsort_axis_by(axis, move |a, b| f(a).cmp(&f(b)))
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    /// Return whether the array is sorted in non-decreasing order.
+    ///
+    /// See [`.is_sorted_axis()`](ArrayBase::is_sorted_axis) for how elements are
+    /// compared.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert!(arr1(&[1., 2., 2., 5.]).is_sorted());
+    /// assert!(!arr1(&[1., f64::NAN, 5.]).is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        A: PartialOrd,
+    {
+        is_sorted_lane(&self.view())
+    }
+}
+
+fn is_sorted_lane<A: PartialOrd>(lane: &ArrayView1<'_, A>) -> bool {
+    lane.iter().zip(lane.iter().skip(1)).all(|(a, b)| a <= b)
+}
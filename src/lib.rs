@@ -76,7 +76,8 @@
 //!     default `std` feature. To do so, use `default-features = false` in
 //!     your `Cargo.toml`.
 //!   - The `geomspace` `linspace` `logspace` `range` `std` `var` `var_axis`
-//!     and `std_axis` methods are only available when `std` is enabled.
+//!     and `std_axis` methods, and the other methods that require `Float` elements
+//!     (like `median_axis` and `describe`), are only available when `std` is enabled.
 //! - `serde`
 //!   - Enables serialization support for serde 1.x
 //! - `rayon`
@@ -84,12 +85,18 @@
 //!   - Implies std
 //! - `approx`
 //!   - Enables implementations of traits from the [`approx`] crate.
+//! - `bytemuck`
+//!   - Enables reinterpreting arrays of plain old data with
+//!     [`.view_bytes_as()`](ArrayBase::view_bytes_as).
 //! - `blas`
 //!   - Enable transparent BLAS support for matrix multiplication.
 //!     Uses ``blas-src`` for pluggable backend, which needs to be configured
 //!     separately (see the README).
 //! - `matrixmultiply-threading`
 //!   - Enable the ``threading`` feature in the matrixmultiply package
+//! - `mmap`
+//!   - Enables arrays backed by memory-mapped files, [`MmapArray`].
+//!   - Implies std
 //!
 //! ## Documentation
 //!
@@ -150,12 +157,16 @@ use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut};
 
 pub use crate::arraytraits::AsArray;
+pub use crate::backend::{Backend, BackendArray, BackendMut, BackendRepr};
+#[cfg(feature = "mmap")]
+pub use crate::mmap::{MmapArray, MmapBackend};
 #[cfg(feature = "std")]
 pub use crate::linalg_traits::NdFloat;
 pub use crate::linalg_traits::LinalgScalar;
 
 #[allow(deprecated)] // stack_new_axis
-pub use crate::stacking::{concatenate, stack, stack_new_axis};
+pub use crate::stacking::{block_diag, concatenate, stack, stack_new_axis, stack_reduce};
+pub use crate::stacking::{elementwise_max, elementwise_mean, elementwise_min};
 
 pub use crate::math_cell::MathCell;
 pub use crate::impl_views::IndexLonger;
@@ -174,6 +185,9 @@ mod array_serde;
 mod arrayformat;
 mod arraytraits;
 pub use crate::argument_traits::AssignElem;
+mod backend;
+#[cfg(feature = "mmap")]
+mod mmap;
 mod data_repr;
 mod data_traits;
 
@@ -1529,6 +1543,11 @@ mod impl_constructors;
 mod impl_methods;
 mod impl_owned_array;
 mod impl_special_element_types;
+mod impl_atomic;
+mod impl_sort;
+mod impl_pad;
+pub use crate::impl_atomic::AtomicAdd;
+pub use crate::impl_pad::PadMode;
 
 /// Private Methods
 impl<A, S, D> ArrayBase<S, D>
@@ -1602,6 +1621,22 @@ mod impl_2d;
 mod impl_dyn;
 
 mod numeric;
+#[cfg(feature = "std")]
+pub use crate::numeric::Summary;
+#[cfg(feature = "std")]
+pub use crate::numeric::{cdist, Metric};
+#[cfg(feature = "std")]
+pub use crate::numeric::{cross_entropy, cross_entropy_soft};
+#[cfg(feature = "std")]
+pub use crate::numeric::{MinMaxScaler, StandardScaler};
+#[cfg(feature = "std")]
+pub use crate::numeric::polyfit;
+pub use crate::numeric::polyval;
+pub use crate::numeric::{
+    accuracy, auc, confusion_matrix, mae, mse, precision_recall_f1, r2_score, roc_curve,
+};
+#[cfg(feature = "std")]
+pub use crate::numeric::rmse;
 
 pub mod linalg;
 
@@ -1611,6 +1646,9 @@ pub use crate::impl_ops::ScalarOperand;
 #[cfg(feature = "approx")]
 mod array_approx;
 
+#[cfg(feature = "bytemuck")]
+mod array_bytemuck;
+
 // Array view methods
 mod impl_views;
 
@@ -12,7 +12,8 @@ use crate::numeric_util;
 use crate::{LinalgScalar, Zip};
 
 use std::any::TypeId;
-use alloc::vec::Vec;
+use std::mem::MaybeUninit;
+use std::ops::Mul;
 
 #[cfg(feature = "blas")]
 use std::cmp;
@@ -269,15 +270,62 @@ where
         let lhs_s0 = a.strides()[0];
         let rhs_s0 = b.strides()[0];
         let column_major = lhs_s0 == 1 && rhs_s0 == 1;
-        // A is Copy so this is safe
-        let mut v = Vec::with_capacity(m * n);
-        let mut c;
-        unsafe {
-            v.set_len(m * n);
-            c = Array::from_shape_vec_unchecked((m, n).set_f(column_major), v);
+        let mut c = Array::uninit((m, n).set_f(column_major));
+        general_mat_mul_uninit(A::one(), &a, &b, c.view_mut());
+        unsafe { c.assume_init() }
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix3>
+where
+    S: Data<Elem = A>,
+{
+    /// Perform a matrix multiplication for each pair of matrices along the first
+    /// axis of `self` and `rhs`.
+    ///
+    /// The array shapes must agree in the way that if `self` is *B* × *M* × *N*,
+    /// then `rhs` is *B* × *N* × *K*. Return a result array with shape
+    /// *B* × *M* × *K*, where `result[i]` is `self[i].dot(rhs[i])`.
+    ///
+    /// **Panics** if shapes are incompatible or the number of elements in the
+    /// result would overflow `isize`.
+    ///
+    /// *Note:* Each product is computed with
+    /// [`general_mat_mul_uninit`](crate::linalg::general_mat_mul_uninit), so it uses blas `gemm`
+    /// for elements of `f32, f64` if enabled and the memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr3;
+    ///
+    /// let a = arr3(&[[[1., 2.]],
+    ///                [[3., 4.]]]);
+    /// let b = arr3(&[[[1.], [1.]],
+    ///                [[2.], [0.]]]);
+    /// assert_eq!(a.batched_dot(&b), arr3(&[[[3.]],
+    ///                                      [[6.]]]));
+    /// ```
+    pub fn batched_dot<S2>(&self, rhs: &ArrayBase<S2, Ix3>) -> Array3<A>
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let ((batch, m, k), (batch2, k2, n)) = (self.dim(), rhs.dim());
+        if batch != batch2 || k != k2 {
+            panic!(
+                "ndarray: inputs {} × {} × {} and {} × {} × {} are not compatible for \
+                 batched matrix multiplication",
+                batch, m, k, batch2, k2, n
+            );
         }
-        mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c.view_mut());
-        c
+        let mut c = Array3::uninit((batch, m, n));
+        Zip::from(self.outer_iter())
+            .and(rhs.outer_iter())
+            .and(c.outer_iter_mut())
+            .for_each(|a, b, c| {
+                general_mat_mul_uninit(A::one(), &a, &b, c);
+            });
+        // every output matrix is written by general_mat_mul_uninit
+        unsafe { c.assume_init() }
     }
 }
 
@@ -344,7 +392,24 @@ where
     ///
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // one gradient descent step, with a learning rate of 0.5
+    /// let mut weights = array![[1., 2.], [3., 4.]];
+    /// let gradient = array![[2., 0.], [-2., 4.]];
+    /// weights.scaled_add(-0.5, &gradient);
+    /// assert_eq!(weights, array![[0., 2.], [4., 2.]]);
+    ///
+    /// // the same update for every row
+    /// weights.scaled_add(2., &array![1., -1.]);
+    /// assert_eq!(weights, array![[2., 0.], [6., 0.]]);
+    /// ```
+    ///
     /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// *Note:* If enabled, uses blas `axpy` for elements of `f32, f64` when
+    /// both arrays have the same shape and the same contiguous memory layout.
     pub fn scaled_add<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
     where
         S: DataMut,
@@ -352,8 +417,65 @@ where
         A: LinalgScalar,
         E: Dimension,
     {
+        #[cfg(feature = "blas")]
+        {
+            if self.scaled_add_blas(alpha, rhs) {
+                return;
+            }
+        }
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
+
+    /// Compute `self += alpha * rhs` with blas `axpy`, if possible.
+    ///
+    /// Return `true` if the operation was performed.
+    #[cfg(feature = "blas")]
+    fn scaled_add_blas<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>) -> bool
+    where
+        S: DataMut,
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+        E: Dimension,
+    {
+        // Use only if the arrays are large enough to be worth it, and only if
+        // the elements correspond one to one in memory order.
+        if self.len() < DOT_BLAS_CUTOFF
+            || self.len() > blas_index::max_value() as usize
+            || self.shape() != rhs.shape()
+            || self.strides() != rhs.strides()
+        {
+            return false;
+        }
+        let x = match rhs.as_slice_memory_order() {
+            Some(x) => x,
+            None => return false,
+        };
+        let y = match self.as_slice_memory_order_mut() {
+            Some(y) => y,
+            None => return false,
+        };
+        macro_rules! axpy {
+            ($ty:ty, $func:ident) => {{
+                if same_type::<A, $ty>() {
+                    unsafe {
+                        blas_sys::$func(
+                            y.len() as blas_index,
+                            cast_as::<A, $ty>(&alpha),
+                            x.as_ptr() as *const $ty,
+                            1,
+                            y.as_mut_ptr() as *mut $ty,
+                            1,
+                        );
+                    }
+                    return true;
+                }
+            }};
+        }
+
+        axpy! {f32, cblas_saxpy};
+        axpy! {f64, cblas_daxpy};
+        false
+    }
 }
 
 // mat_mul_impl uses ArrayView arguments to send all array kinds into
@@ -577,6 +699,58 @@ pub fn general_mat_mul<A, S1, S2, S3>(
     }
 }
 
+/// General matrix-matrix multiplication into an uninitialized output.
+///
+/// Compute C ← α A B
+///
+/// This is [`general_mat_mul`] with β = 0, except that `c` may be uninitialized:
+/// its elements are only written, never read. Return `c` as an initialized view.
+///
+/// The array shapes must agree in the way that
+/// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
+/// layout allows.  The default matrixmultiply backend is otherwise used for
+/// `f32, f64` for all memory layouts.
+///
+/// ```
+/// use ndarray::{arr2, Array2};
+/// use ndarray::linalg::general_mat_mul_uninit;
+///
+/// let a = arr2(&[[1., 2.],
+///                [0., 1.]]);
+/// let b = arr2(&[[1., 2.],
+///                [2., 3.]]);
+/// let mut c = Array2::uninit((2, 2));
+/// general_mat_mul_uninit(1., &a, &b, c.view_mut());
+/// let c = unsafe { c.assume_init() };
+/// assert_eq!(c, a.dot(&b));
+/// ```
+pub fn general_mat_mul_uninit<'c, A, S1, S2>(
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+    c: ArrayViewMut2<'c, MaybeUninit<A>>,
+) -> ArrayViewMut2<'c, A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    }
+    // A is Copy, and c is written without being read when beta is zero
+    unsafe {
+        let mut c = c.into_raw_view_mut().cast::<A>().deref_into_view_mut();
+        mat_mul_impl(alpha, &a.view(), &b.view(), A::zero(), &mut c);
+        c
+    }
+}
+
 /// General matrix-vector multiplication.
 ///
 /// Compute y ← α A x + β y
@@ -691,6 +865,111 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
     }
 }
 
+/// Kronecker product of 2D matrices.
+///
+/// The result is a block matrix of shape (*M* × *P*, *N* × *Q*) for an *M* × *N*
+/// matrix `a` and a *P* × *Q* matrix `b`, where block *(i, j)* is `a[(i, j)] * b`.
+///
+/// ***Panics*** if the number of rows or columns of the result overflows `usize`.
+///
+/// ```
+/// use ndarray::{arr2, linalg::kron};
+///
+/// let a = arr2(&[[1, 2],
+///                [3, 4]]);
+/// let b = arr2(&[[0, 1],
+///                [1, 0]]);
+/// assert_eq!(kron(&a, &b), arr2(&[[0, 1, 0, 2],
+///                                 [1, 0, 2, 0],
+///                                 [0, 3, 0, 4],
+///                                 [3, 0, 4, 0]]));
+/// ```
+pub fn kron<A, S1, S2>(a: &ArrayBase<S1, Ix2>, b: &ArrayBase<S2, Ix2>) -> Array2<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: Clone + Mul<Output = A>,
+{
+    let ((m, n), (p, q)) = (a.dim(), b.dim());
+    let dim = match (m.checked_mul(p), n.checked_mul(q)) {
+        (Some(rows), Some(cols)) => (rows, cols),
+        _ => panic!(
+            "ndarray: kron of shapes {:?} and {:?} overflows",
+            a.shape(),
+            b.shape()
+        ),
+    };
+    let mut res = Array2::uninit(dim);
+    if p != 0 && q != 0 {
+        Zip::from(res.exact_chunks_mut((p, q)))
+            .and(a)
+            .for_each(|block, x| {
+                Zip::from(block).and(b).for_each(|out, y| {
+                    *out = MaybeUninit::new(x.clone() * y.clone());
+                })
+            });
+    }
+    // every element is written by exactly one block, or the result is empty
+    unsafe { res.assume_init() }
+}
+
+/// Outer product of two vectors.
+///
+/// The result has shape (*M*, *N*) for vectors of lengths *M* and *N*, and its
+/// element at *(i, j)* is `a[i] * b[j]`. See [`outer_with`] for other element
+/// functions.
+///
+/// ```
+/// use ndarray::{arr1, arr2, linalg::outer};
+///
+/// let a = arr1(&[1, 2, 3]);
+/// let b = arr1(&[1, 10]);
+/// assert_eq!(outer(&a, &b), arr2(&[[1, 10],
+///                                  [2, 20],
+///                                  [3, 30]]));
+/// ```
+pub fn outer<A, B, C, S1, S2>(a: &ArrayBase<S1, Ix1>, b: &ArrayBase<S2, Ix1>) -> Array2<C>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = B>,
+    A: Clone + Mul<B, Output = C>,
+    B: Clone,
+{
+    outer_with(a, b, |x, y| x.clone() * y.clone())
+}
+
+/// Generalized outer product of two vectors, where the element at *(i, j)* of the
+/// (*M*, *N*) result is `f(&a[i], &b[j])`.
+///
+/// ```
+/// use ndarray::{arr1, arr2, linalg::outer_with};
+///
+/// let a = arr1(&[1, 2, 3]);
+/// let b = arr1(&[1, 2]);
+/// assert_eq!(outer_with(&a, &b, |&x, &y| x - y), arr2(&[[0, -1],
+///                                                      [1, 0],
+///                                                      [2, 1]]));
+/// ```
+pub fn outer_with<A, B, C, S1, S2, F>(
+    a: &ArrayBase<S1, Ix1>,
+    b: &ArrayBase<S2, Ix1>,
+    f: F,
+) -> Array2<C>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = B>,
+    F: FnMut(&A, &B) -> C,
+{
+    let dim = (a.len(), b.len());
+    let column = a.view().insert_axis(Axis(1));
+    let row = b.view().insert_axis(Axis(0));
+    // the broadcasts only fail if the number of elements overflows
+    let column = column.broadcast(dim).unwrap_or_else(|| {
+        panic!("ndarray: outer product of shape {:?} overflows isize", dim)
+    });
+    Zip::from(column).and_broadcast(&row).map_collect(f)
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: 'static, B: 'static>() -> bool {
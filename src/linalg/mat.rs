@@ -0,0 +1,73 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Mul;
+
+use crate::imp_prelude::*;
+use crate::LinalgScalar;
+
+/// A matrix view for which `*` is matrix multiplication.
+///
+/// For arrays, the arithmetic operators are elementwise, so `&a * &b` multiplies
+/// corresponding elements of `a` and `b`. Wrapping two 2D arrays with
+/// [`.mat()`](ArrayBase::mat) instead makes `a.mat() * b.mat()` the matrix product
+/// `a.dot(&b)`, matching mathematical notation.
+///
+/// ```
+/// use ndarray::arr2;
+///
+/// let a = arr2(&[[1., 2.],
+///                [0., 1.]]);
+/// let b = arr2(&[[1., 2.],
+///                [2., 3.]]);
+///
+/// // matrix multiplication
+/// assert_eq!(a.mat() * b.mat(), arr2(&[[5., 8.],
+///                                      [2., 3.]]));
+/// // elementwise multiplication
+/// assert_eq!(&a * &b, arr2(&[[1., 4.],
+///                            [0., 3.]]));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Mat<'a, A> {
+    view: ArrayView2<'a, A>,
+}
+
+impl<'a, A> Mat<'a, A> {
+    /// Return the wrapped matrix view.
+    pub fn view(&self) -> ArrayView2<'a, A> {
+        self.view
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return a view of the matrix for which `*` is matrix multiplication instead of
+    /// elementwise multiplication; see [`Mat`].
+    pub fn mat(&self) -> Mat<'_, A> {
+        Mat { view: self.view() }
+    }
+}
+
+/// Perform matrix multiplication of `self` and `rhs`, like
+/// [`.dot()`](ArrayBase::dot).
+///
+/// **Panics** if shapes are incompatible or the number of elements in the result would
+/// overflow `isize`.
+impl<'a, 'b, A> Mul<Mat<'b, A>> for Mat<'a, A>
+where
+    A: LinalgScalar,
+{
+    type Output = Array2<A>;
+
+    fn mul(self, rhs: Mat<'b, A>) -> Array2<A> {
+        self.view.dot(&rhs.view)
+    }
+}
@@ -9,7 +9,12 @@
 //! Linear algebra.
 
 pub use self::impl_linalg::general_mat_mul;
+pub use self::impl_linalg::general_mat_mul_uninit;
 pub use self::impl_linalg::general_mat_vec_mul;
+pub use self::impl_linalg::kron;
+pub use self::impl_linalg::{outer, outer_with};
 pub use self::impl_linalg::Dot;
+pub use self::mat::Mat;
 
 mod impl_linalg;
+mod mat;
@@ -0,0 +1,122 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arrays backed by memory-mapped files.
+
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::path::Path;
+
+use bytemuck::Pod;
+use memmap2::Mmap;
+
+use crate::backend::{Backend, BackendArray};
+use crate::imp_prelude::*;
+use crate::StrideShape;
+
+/// Read-only element storage in a memory-mapped file.
+///
+/// The backend owns the memory map, so the map stays alive for as long as any array
+/// using it.
+///
+/// *Don’t use this type directly—use the type alias
+/// [`MmapArray`](type.MmapArray.html) for the array type!*
+#[derive(Debug)]
+pub struct MmapBackend<A> {
+    map: Mmap,
+    offset: usize,
+    len: usize,
+    marker: PhantomData<A>,
+}
+
+unsafe impl<A: Pod> Backend for MmapBackend<A> {
+    type Elem = A;
+
+    fn as_ptr(&self) -> *const A {
+        self.map[self.offset..].as_ptr() as *const A
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// A read-only array whose elements are stored in a memory-mapped file.
+///
+/// Use [`.view()`](ArrayBase::view) to get an [`ArrayView`] of it; the view borrows the
+/// array, which keeps the file mapped.
+pub type MmapArray<A, D> = BackendArray<MmapBackend<A>, D>;
+
+/// # Methods For Memory-Mapped Arrays
+impl<A, D> MmapArray<A, D>
+where
+    A: Pod,
+    D: Dimension,
+{
+    /// Map the file at `path` into memory and use it as the elements of an array with
+    /// the given shape.
+    ///
+    /// The elements start `offset` bytes into the file and extend to the end of the file,
+    /// in the native byte order. With custom strides, elements past the last one the
+    /// shape reaches are left unused.
+    ///
+    /// **Errors** if the file can not be opened or mapped, if `offset` is past the end of
+    /// the file or not a multiple of the alignment of `A`, if `A` is zero-sized, if the
+    /// file does not end on a whole element, or if `shape` does not correspond to the
+    /// number of elements in the file (errors of kind `InvalidInput`).
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while the
+    /// array is alive.
+    ///
+    /// ```no_run
+    /// use ndarray::{Ix2, MmapArray};
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let a = unsafe { MmapArray::<f64, Ix2>::from_mmap("data.bin", (1000, 1000), 0)? };
+    /// println!("{}", a.sum());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn from_mmap<P, Sh>(path: P, shape: Sh, offset: usize) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+        Sh: Into<StrideShape<D>>,
+    {
+        let size = mem::size_of::<A>();
+        if size == 0 {
+            return Err(invalid_input("zero-sized element type"));
+        }
+        let map = Mmap::map(&File::open(path)?)?;
+        if offset > map.len() {
+            return Err(invalid_input("offset is past the end of the file"));
+        }
+        // The map itself is page-aligned, so only the offset needs checking
+        if offset & (mem::align_of::<A>() - 1) != 0 {
+            return Err(invalid_input("offset is not aligned for the element type"));
+        }
+        let len = (map.len() - offset) / size;
+        if offset + len * size != map.len() {
+            return Err(invalid_input("file does not end on a whole element"));
+        }
+        let backend = MmapBackend {
+            map,
+            offset,
+            len,
+            marker: PhantomData,
+        };
+        Self::from_backend(shape, backend).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+}
+
+fn invalid_input(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
@@ -0,0 +1,184 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// A distance metric between two vectors, for [`pdist`](ArrayBase::pdist) and
+/// [`cdist`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// The Euclidean distance `sqrt(Σ (a_i - b_i)²)`.
+    Euclidean,
+    /// The Manhattan (city block) distance `Σ |a_i - b_i|`.
+    Manhattan,
+    /// The cosine distance `1 - a · b / (|a| |b|)`, which is NaN if either vector
+    /// is zero.
+    Cosine,
+    /// The Chebyshev distance `max |a_i - b_i|`.
+    Chebyshev,
+}
+
+impl Metric {
+    /// Return the distance between `a` and `b`, which have the same length.
+    fn distance<A: Float>(self, a: ArrayView1<'_, A>, b: ArrayView1<'_, A>) -> A {
+        let zip = Zip::from(&a).and(&b);
+        match self {
+            Metric::Euclidean => zip
+                .fold(A::zero(), |acc, &x, &y| acc + (x - y) * (x - y))
+                .sqrt(),
+            Metric::Manhattan => zip.fold(A::zero(), |acc, &x, &y| acc + (x - y).abs()),
+            Metric::Cosine => {
+                let (dot, a2, b2) = zip.fold((A::zero(), A::zero(), A::zero()), |(d, a2, b2), &x, &y| {
+                    (d + x * y, a2 + x * x, b2 + y * y)
+                });
+                A::one() - dot / (a2.sqrt() * b2.sqrt())
+            }
+            Metric::Chebyshev => zip.fold(A::zero(), |acc, &x, &y| acc.max((x - y).abs())),
+        }
+    }
+}
+
+/// # Distance Methods
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Float,
+    S: Data<Elem = A>,
+{
+    /// Return the matrix of distances between all pairs of rows of `self`, under
+    /// `metric`.
+    ///
+    /// Element `[i, j]` of the result is the distance between row `i` and row `j`. The
+    /// result is symmetric, and its diagonal is zero (except for NaN cosine distances
+    /// of zero rows).
+    ///
+    /// ```
+    /// use ndarray::{arr2, Metric};
+    ///
+    /// let points = arr2(&[[0., 0.],
+    ///                     [3., 4.],
+    ///                     [6., 8.]]);
+    /// assert_eq!(
+    ///     points.pdist(Metric::Euclidean),
+    ///     arr2(&[[0., 5., 10.],
+    ///            [5., 0., 5.],
+    ///            [10., 5., 0.]])
+    /// );
+    /// ```
+    pub fn pdist(&self, metric: Metric) -> Array2<A> {
+        let n = self.nrows();
+        let mut res = Array2::zeros((n, n));
+        for i in 0..n {
+            for j in i..n {
+                let d = metric.distance(self.row(i), self.row(j));
+                res[[i, j]] = d;
+                res[[j, i]] = d;
+            }
+        }
+        res
+    }
+
+    /// Find the `k` nearest rows of `self` to each row of `query`, under `metric`.
+    ///
+    /// Return the indices of the nearest rows of `self` and their distances, with one
+    /// row per query row, ordered from nearest to farthest. Rows at equal distance are
+    /// ordered by index, and NaN distances come last.
+    ///
+    /// This computes the distances between all pairs of rows with [`cdist`], so it is
+    /// a simple baseline that takes time proportional to
+    /// `self.nrows() * query.nrows() * self.ncols()`.
+    ///
+    /// **Panics** if `k` is larger than the number of rows of `self`, or if `self` and
+    /// `query` have a different number of columns.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Metric};
+    ///
+    /// let points = arr2(&[[0., 0.],
+    ///                     [5., 5.],
+    ///                     [1., 0.],
+    ///                     [4., 4.]]);
+    /// let (indices, distances) = points.knn(&arr2(&[[0., 1.], [6., 6.]]), 2, Metric::Manhattan);
+    /// assert_eq!(indices, arr2(&[[0, 2], [1, 3]]));
+    /// assert_eq!(distances, arr2(&[[1., 2.], [2., 4.]]));
+    /// ```
+    pub fn knn<S2>(&self, query: &ArrayBase<S2, Ix2>, k: usize, metric: Metric)
+        -> (Array2<usize>, Array2<A>)
+    where
+        S2: Data<Elem = A>,
+    {
+        let n = self.nrows();
+        assert!(
+            k <= n,
+            "ndarray: knn with k = {} requires at least {} rows, got {}",
+            k,
+            k,
+            n
+        );
+        let distances = cdist(query, self, metric);
+        let mut indices = Array2::zeros((query.nrows(), k));
+        let mut nearest = Array2::zeros((query.nrows(), k));
+        let mut order = Vec::with_capacity(n);
+        for (i, row) in distances.outer_iter().enumerate() {
+            order.clear();
+            order.extend(0..n);
+            order.sort_by(|&a, &b| match row[a].partial_cmp(&row[b]) {
+                Some(ord) => ord,
+                None => row[a].is_nan().cmp(&row[b].is_nan()),
+            });
+            for (j, &index) in order[..k].iter().enumerate() {
+                indices[[i, j]] = index;
+                nearest[[i, j]] = row[index];
+            }
+        }
+        (indices, nearest)
+    }
+}
+
+/// Return the matrix of distances between the rows of `a` and the rows of `b`, under
+/// `metric`.
+///
+/// Element `[i, j]` of the result is the distance between row `i` of `a` and row `j`
+/// of `b`.
+///
+/// **Panics** if `a` and `b` have a different number of columns.
+///
+/// ```
+/// use ndarray::{arr2, cdist, Metric};
+///
+/// let a = arr2(&[[0., 0.],
+///                [1., 1.]]);
+/// let b = arr2(&[[1., 0.],
+///                [4., 5.],
+///                [-1., 3.]]);
+/// assert_eq!(
+///     cdist(&a, &b, Metric::Manhattan),
+///     arr2(&[[1., 9., 4.],
+///            [1., 7., 4.]])
+/// );
+/// ```
+pub fn cdist<A, S, S2>(a: &ArrayBase<S, Ix2>, b: &ArrayBase<S2, Ix2>, metric: Metric) -> Array2<A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    assert_eq!(
+        a.ncols(),
+        b.ncols(),
+        "ndarray: cdist requires arrays with the same number of columns"
+    );
+    Array2::from_shape_fn((a.nrows(), b.nrows()), |(i, j)| {
+        metric.distance(a.row(i), b.row(j))
+    })
+}
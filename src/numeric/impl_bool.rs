@@ -0,0 +1,84 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::FoldWhile;
+
+/// # Methods for Boolean Arrays
+impl<S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    /// Return `true` if all elements of the array are `true`.
+    ///
+    /// Stops at the first `false` element. Returns `true` for an empty array.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[true, true],
+    ///                [false, true]]);
+    /// assert!(!a.all());
+    /// assert!(a.row(0).all());
+    /// ```
+    pub fn all(&self) -> bool {
+        self.fold_while(true, |_, &x| {
+            if x {
+                FoldWhile::Continue(true)
+            } else {
+                FoldWhile::Done(false)
+            }
+        })
+    }
+
+    /// Return `true` if any element of the array is `true`.
+    ///
+    /// Stops at the first `true` element. Returns `false` for an empty array.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[true, false],
+    ///                [false, false]]);
+    /// assert!(a.any());
+    /// assert!(!a.row(1).any());
+    /// ```
+    pub fn any(&self) -> bool {
+        self.fold_while(false, |_, &x| {
+            if x {
+                FoldWhile::Done(true)
+            } else {
+                FoldWhile::Continue(false)
+            }
+        })
+    }
+
+    /// Return the running count of `true` elements along `axis`.
+    ///
+    /// The result has the same shape as `self`; each element is the number of `true`
+    /// elements up to and including that position in its lane.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr1(&[true, false, true, true]);
+    /// assert_eq!(a.cumsum_bool_axis(Axis(0)), arr1(&[1, 1, 2, 3]));
+    ///
+    /// let b = arr2(&[[true, false],
+    ///                [true, true]]);
+    /// assert_eq!(b.cumsum_bool_axis(Axis(1)), arr2(&[[1, 1], [1, 2]]));
+    /// ```
+    pub fn cumsum_bool_axis(&self, axis: Axis) -> Array<usize, D> {
+        let mut counts = self.map(|&x| x as usize);
+        counts.accumulate_axis_inplace(axis, |&prev, curr| *curr += prev);
+        counts
+    }
+}
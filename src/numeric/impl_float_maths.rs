@@ -0,0 +1,269 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+macro_rules! unary_ops {
+    ($($id:ident, $inplace:ident: $doc:expr, $doc_inplace:expr;)+) => {
+        $(
+        #[doc = $doc]
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $id(&self) -> Array<A, D> {
+            self.mapv(A::$id)
+        }
+
+        #[doc = $doc_inplace]
+        pub fn $inplace(&mut self)
+        where
+            S: DataMut,
+        {
+            self.mapv_inplace(A::$id)
+        }
+        )+
+    };
+}
+
+/// Return the logistic sigmoid of `x`, computed without overflow in `exp`.
+fn sigmoid<A: Float>(x: A) -> A {
+    if x >= A::zero() {
+        A::one() / (A::one() + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (A::one() + e)
+    }
+}
+
+/// Return the GELU of `x`, using the tanh approximation.
+fn gelu<A: Float>(x: A) -> A {
+    let half = A::from(0.5).unwrap();
+    let c = A::from(0.044_715).unwrap();
+    let sqrt_2_over_pi = A::from(0.797_884_560_802_865_4).unwrap();
+    half * x * (A::one() + (sqrt_2_over_pi * (x + c * x * x * x)).tanh())
+}
+
+/// # Element-wise Methods for Float Arrays
+///
+/// These methods require crate feature `std`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the element-wise length of the hypotenuse `sqrt(self² + rhs²)`.
+    ///
+    /// Computed with [`Float::hypot`], which avoids overflow and underflow in the
+    /// intermediate squares.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr0, arr1};
+    ///
+    /// let a = arr1(&[3., 5., 1e300]);
+    /// assert_eq!(a.hypot(&arr1(&[4., 12., 1e300])), arr1(&[5., 13., 1e300 * 2f64.sqrt()]));
+    /// assert_eq!(a.hypot(&arr0(0.)), a);
+    /// ```
+    pub fn hypot<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).map_collect(|&x, &y| x.hypot(y))
+    }
+
+    /// Return the element-wise four quadrant arctangent of `self` (y) and `rhs` (x),
+    /// in radians.
+    ///
+    /// The result is the angle of the point `(x, y)` in the range `[-π, π]`; see
+    /// [`Float::atan2`].
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    /// use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    ///
+    /// let y = arr1(&[1., 1., -1., 0.]);
+    /// let x = arr1(&[1., 0., -1., -1.]);
+    /// assert_eq!(y.atan2(&x), arr1(&[FRAC_PI_4, FRAC_PI_2, -3. * FRAC_PI_4, 2. * FRAC_PI_2]));
+    /// ```
+    pub fn atan2<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).map_collect(|&y, &x| y.atan2(x))
+    }
+
+    unary_ops! {
+        sin, sin_inplace:
+            "Return the sine of each element (in radians).",
+            "Replace each element with its sine (in radians).";
+        cos, cos_inplace:
+            "Return the cosine of each element (in radians).",
+            "Replace each element with its cosine (in radians).";
+        tan, tan_inplace:
+            "Return the tangent of each element (in radians).",
+            "Replace each element with its tangent (in radians).";
+        tanh, tanh_inplace:
+            "Return the hyperbolic tangent of each element.",
+            "Replace each element with its hyperbolic tangent.";
+        exp, exp_inplace:
+            "Return `e^x` for each element `x`.",
+            "Replace each element `x` with `e^x`.";
+        ln, ln_inplace:
+            "Return the natural logarithm of each element.",
+            "Replace each element with its natural logarithm.";
+        log10, log10_inplace:
+            "Return the base 10 logarithm of each element.",
+            "Replace each element with its base 10 logarithm.";
+        log2, log2_inplace:
+            "Return the base 2 logarithm of each element.",
+            "Replace each element with its base 2 logarithm.";
+        sqrt, sqrt_inplace:
+            "Return the square root of each element; NaN for negative elements.",
+            "Replace each element with its square root; NaN for negative elements.";
+        cbrt, cbrt_inplace:
+            "Return the cube root of each element.",
+            "Replace each element with its cube root.";
+    }
+
+    /// Return the logistic sigmoid `1 / (1 + e^-x)` of each element `x`.
+    ///
+    /// The result is in the range `[0, 1]`, and large inputs of either sign don’t
+    /// overflow.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[0., -1000., 1000.]);
+    /// assert_eq!(a.sigmoid(), arr1(&[0.5, 0., 1.]));
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn sigmoid(&self) -> Array<A, D> {
+        self.mapv(sigmoid)
+    }
+
+    /// Replace each element `x` with its logistic sigmoid `1 / (1 + e^-x)`.
+    pub fn sigmoid_inplace(&mut self)
+    where
+        S: DataMut,
+    {
+        self.mapv_inplace(sigmoid)
+    }
+
+    /// Return the rectified linear unit `max(x, 0)` of each element `x`.
+    ///
+    /// NaN elements stay NaN.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[-2., 0., 3.]).relu(), arr1(&[0., 0., 3.]));
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn relu(&self) -> Array<A, D> {
+        self.leaky_relu(A::zero())
+    }
+
+    /// Replace each element `x` with its rectified linear unit `max(x, 0)`.
+    ///
+    /// NaN elements stay NaN.
+    pub fn relu_inplace(&mut self)
+    where
+        S: DataMut,
+    {
+        self.leaky_relu_inplace(A::zero())
+    }
+
+    /// Return the leaky rectified linear unit of each element `x`: `x` if it is
+    /// positive, otherwise `slope * x`.
+    ///
+    /// NaN elements stay NaN.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[-2., 0., 3.]).leaky_relu(0.1), arr1(&[-0.2, 0., 3.]));
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn leaky_relu(&self, slope: A) -> Array<A, D> {
+        self.mapv(move |x| if x < A::zero() { slope * x } else { x })
+    }
+
+    /// Replace each element `x` with its leaky rectified linear unit: `x` if it is
+    /// positive, otherwise `slope * x`.
+    ///
+    /// NaN elements stay NaN.
+    pub fn leaky_relu_inplace(&mut self, slope: A)
+    where
+        S: DataMut,
+    {
+        self.mapv_inplace(move |x| if x < A::zero() { slope * x } else { x })
+    }
+
+    /// Return the exponential linear unit of each element `x`: `x` if it is positive,
+    /// otherwise `alpha * (e^x - 1)`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[-1000., 0., 3.]).elu(1.5);
+    /// assert_eq!(a, arr1(&[-1.5, 0., 3.]));
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn elu(&self, alpha: A) -> Array<A, D> {
+        self.mapv(move |x| if x > A::zero() { x } else { alpha * x.exp_m1() })
+    }
+
+    /// Replace each element `x` with its exponential linear unit: `x` if it is
+    /// positive, otherwise `alpha * (e^x - 1)`.
+    pub fn elu_inplace(&mut self, alpha: A)
+    where
+        S: DataMut,
+    {
+        self.mapv_inplace(move |x| if x > A::zero() { x } else { alpha * x.exp_m1() })
+    }
+
+    /// Return the Gaussian error linear unit of each element `x`.
+    ///
+    /// The GELU is `x * Φ(x)`, where `Φ` is the standard normal cumulative distribution
+    /// function. It is computed with the usual tanh approximation:
+    ///
+    /// `0.5 * x * (1 + tanh(sqrt(2 / π) * (x + 0.044715 * x³)))`
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[-10., 0., 10.]).gelu();
+    /// assert_eq!(a, arr1(&[-0., 0., 10.]));
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn gelu(&self) -> Array<A, D> {
+        self.mapv(gelu)
+    }
+
+    /// Replace each element `x` with its Gaussian error linear unit, using the tanh
+    /// approximation (see [`.gelu()`](Self::gelu)).
+    pub fn gelu_inplace(&mut self)
+    where
+        S: DataMut,
+    {
+        self.mapv_inplace(gelu)
+    }
+}
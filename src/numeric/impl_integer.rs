@@ -0,0 +1,224 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+use num_traits::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use num_traits::{WrappingAdd, WrappingMul, WrappingSub};
+use std::mem::MaybeUninit;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+macro_rules! zip_int_methods {
+    ($($(#[$meta:meta])* fn $name:ident where A: $trt:ident;)+) => {
+        $(
+        $(#[$meta])*
+        pub fn $name<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+        where
+            A: $trt,
+            S2: Data<Elem = A>,
+            E: Dimension,
+        {
+            let rhs = rhs.broadcast_unwrap(self.raw_dim());
+            Zip::from(self).and(&rhs).map_collect(|x, y| x.$name(y))
+        }
+        )+
+    };
+}
+
+/// # Integer Arithmetic Methods
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the element-wise sum of `self` and `rhs`, or `None` if any of the
+    /// additions overflows.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    /// Stops at the first overflow.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[100i8, -100]);
+    /// assert_eq!(a.checked_add(&arr1(&[27, -28])), Some(arr1(&[127, -128])));
+    /// assert_eq!(a.checked_add(&arr1(&[28, 0])), None);
+    /// ```
+    pub fn checked_add<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Option<Array<A, D>>
+    where
+        A: CheckedAdd,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.zip_checked(rhs, A::checked_add)
+    }
+
+    /// Return the element-wise difference of `self` and `rhs`, or `None` if any of
+    /// the subtractions overflows.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    /// Stops at the first overflow.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[3u8, 5], [4, 1]]);
+    /// assert_eq!(a.checked_sub(&arr1(&[1, 0])), Some(arr2(&[[2, 5], [3, 1]])));
+    /// assert_eq!(a.checked_sub(&arr1(&[0, 2])), None);
+    /// ```
+    pub fn checked_sub<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Option<Array<A, D>>
+    where
+        A: CheckedSub,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.zip_checked(rhs, A::checked_sub)
+    }
+
+    /// Return the element-wise product of `self` and `rhs`, or `None` if any of the
+    /// multiplications overflows.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    /// Stops at the first overflow.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr0, arr1};
+    ///
+    /// let a = arr1(&[1000u16, 6553]);
+    /// assert_eq!(a.checked_mul(&arr0(10)), Some(arr1(&[10000, 65530])));
+    /// assert_eq!(a.checked_mul(&arr0(11)), None);
+    /// ```
+    pub fn checked_mul<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Option<Array<A, D>>
+    where
+        A: CheckedMul,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.zip_checked(rhs, A::checked_mul)
+    }
+
+    zip_int_methods! {
+        /// Return the element-wise sum of `self` and `rhs`, saturating at the numeric
+        /// bounds instead of overflowing.
+        ///
+        /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        ///
+        /// ```
+        /// use ndarray::arr1;
+        ///
+        /// let pixels = arr1(&[10u8, 200, 250]);
+        /// assert_eq!(pixels.saturating_add(&arr1(&[50, 50, 50])), arr1(&[60, 250, 255]));
+        /// ```
+        fn saturating_add where A: SaturatingAdd;
+        /// Return the element-wise difference of `self` and `rhs`, saturating at the
+        /// numeric bounds instead of overflowing.
+        ///
+        /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        ///
+        /// ```
+        /// use ndarray::{arr0, arr1};
+        ///
+        /// let pixels = arr1(&[10u8, 200, 250]);
+        /// assert_eq!(pixels.saturating_sub(&arr0(50)), arr1(&[0, 150, 200]));
+        /// ```
+        fn saturating_sub where A: SaturatingSub;
+        /// Return the element-wise product of `self` and `rhs`, saturating at the
+        /// numeric bounds instead of overflowing.
+        ///
+        /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        ///
+        /// ```
+        /// use ndarray::{arr0, arr1};
+        ///
+        /// let a = arr1(&[-50i8, 30, 70]);
+        /// assert_eq!(a.saturating_mul(&arr0(2)), arr1(&[-100, 60, 127]));
+        /// ```
+        fn saturating_mul where A: SaturatingMul;
+        /// Return the element-wise sum of `self` and `rhs`, wrapping around at the
+        /// boundary of the type.
+        ///
+        /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        ///
+        /// ```
+        /// use ndarray::arr1;
+        ///
+        /// let a = arr1(&[10u8, 200, 250]);
+        /// assert_eq!(a.wrapping_add(&arr1(&[50, 50, 50])), arr1(&[60, 250, 44]));
+        /// ```
+        fn wrapping_add where A: WrappingAdd;
+        /// Return the element-wise difference of `self` and `rhs`, wrapping around at
+        /// the boundary of the type.
+        ///
+        /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        ///
+        /// ```
+        /// use ndarray::{arr0, arr1};
+        ///
+        /// let a = arr1(&[10u8, 200, 250]);
+        /// assert_eq!(a.wrapping_sub(&arr0(50)), arr1(&[216, 150, 200]));
+        /// ```
+        fn wrapping_sub where A: WrappingSub;
+        /// Return the element-wise product of `self` and `rhs`, wrapping around at the
+        /// boundary of the type.
+        ///
+        /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        ///
+        /// ```
+        /// use ndarray::{arr0, arr1};
+        ///
+        /// let a = arr1(&[-50i8, 30, 70]);
+        /// assert_eq!(a.wrapping_mul(&arr0(2)), arr1(&[-100, 60, -116]));
+        /// ```
+        fn wrapping_mul where A: WrappingMul;
+    }
+
+    /// Apply `f` to the pairs of elements of `self` and `rhs` (broadcast to the shape of
+    /// `self`), stopping at the first `None`.
+    fn zip_checked<B, S2, E, F>(&self, rhs: &ArrayBase<S2, E>, mut f: F) -> Option<Array<B, D>>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension,
+        F: FnMut(&A, &A) -> Option<B>,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        let zip = Zip::from(self).and(&rhs);
+        let mut res = zip.uninitalized_for_current_layout();
+        let complete = zip.and(&mut res).all(|x, y, out| match f(x, y) {
+            Some(value) => {
+                *out = MaybeUninit::new(value);
+                true
+            }
+            None => false,
+        });
+        if complete {
+            // safe because: all elements were written
+            unsafe { Some(res.assume_init()) }
+        } else {
+            None
+        }
+    }
+}
@@ -8,12 +8,13 @@
 
 #[cfg(feature = "std")]
 use num_traits::Float;
-use num_traits::{self, FromPrimitive, Zero};
-use std::ops::{Add, Div, Mul};
+use num_traits::{self, FromPrimitive, One, Zero};
+use std::ops::{Add, Div, Mul, Sub};
 
 use crate::imp_prelude::*;
 use crate::itertools::enumerate;
 use crate::numeric_util;
+use crate::{Slice, Zip};
 
 /// # Numerical Methods for Arrays
 impl<A, S, D> ArrayBase<S, D>
@@ -71,6 +72,15 @@ where
     ///
     /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
     ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 5., 6.]]);
+    /// assert_eq!(a.mean(), Some(3.5));
+    /// assert_eq!(Array2::<f64>::zeros((2, 0)).mean(), None);
+    /// ```
+    ///
     /// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
     pub fn mean(&self) -> Option<A>
     where
@@ -113,6 +123,45 @@ where
         sum
     }
 
+    /// Return the number of non-zero elements in the array.
+    ///
+    /// An element is zero if `.is_zero()` is true for it, so for floats both `0.0` and
+    /// `-0.0` count as zero (and NaN counts as non-zero).
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[0., 2., -0.],
+    ///                [4., 0., 6.]]);
+    /// assert_eq!(a.count_nonzero(), 3);
+    /// ```
+    pub fn count_nonzero(&self) -> usize
+    where
+        A: Zero,
+    {
+        self.fold(0, |count, elt| count + !elt.is_zero() as usize)
+    }
+
+    /// Return an array of the same shape with one where the element is strictly greater
+    /// than `threshold` and zero elsewhere, keeping the element type.
+    ///
+    /// NaN elements and a NaN `threshold` give zero, since they don't compare greater.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[0.2, 0.5],
+    ///                [0.7, -1.]]);
+    /// assert_eq!(a.binarize(0.5), arr2(&[[0., 0.],
+    ///                                    [1., 0.]]));
+    /// ```
+    pub fn binarize(&self, threshold: A) -> Array<A, D>
+    where
+        A: PartialOrd + Zero + One,
+    {
+        self.map(|x| if *x > threshold { A::one() } else { A::zero() })
+    }
+
     /// Return variance of elements in the array.
     ///
     /// The variance is computed using the [Welford one-pass
@@ -418,4 +467,257 @@ where
     {
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
+
+    /// Return the median along `axis`.
+    ///
+    /// The median of a lane with an even number of elements is the mean of the two
+    /// middle elements. The median of a lane that contains a NaN is NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 5., 3.],
+    ///                [4., 2., 9.]]);
+    /// assert_eq!(a.median_axis(Axis(1)), aview1(&[3., 4.]));
+    /// assert_eq!(a.median_axis(Axis(0)), aview1(&[2.5, 3.5, 6.]));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn median_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        assert!(n > 0, "ndarray: can not compute the median along an axis of length zero");
+        let mid = n / 2;
+        let mut buf = Vec::with_capacity(n);
+        self.map_axis(axis, |lane| {
+            buf.clear();
+            buf.extend(lane.iter().cloned());
+            if buf.iter().any(|x| x.is_nan()) {
+                return A::nan();
+            }
+            // No NaNs, so the comparison is total
+            let (lower, &mut upper, _) =
+                buf.select_nth_unstable_by(mid, |a, b| a.partial_cmp(b).unwrap());
+            if n % 2 == 1 {
+                upper
+            } else {
+                let lower = lower.iter().cloned().fold(A::neg_infinity(), A::max);
+                (lower + upper) / (A::one() + A::one())
+            }
+        })
+    }
+
+    /// Return the logarithm of the sum of exponentials, `ln(Σ e^x)`, along `axis`.
+    ///
+    /// The largest element of each lane is subtracted before taking exponentials, so
+    /// the result does not overflow even for large elements. The result for a lane
+    /// of length zero is negative infinity (the logarithm of an empty sum), and the
+    /// result for a lane that contains a NaN is NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[0., 0.],
+    ///                [1000., 1000.]]);
+    /// let lse = a.logsumexp_axis(Axis(1));
+    /// assert_eq!(lse, aview1(&[2f64.ln(), 1000. + 2f64.ln()]));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn logsumexp_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| {
+            let max = lane.fold(A::neg_infinity(), |acc, &x| acc.max(x));
+            if max.is_infinite() {
+                // All -inf (or empty): the sum is zero. Any +inf: the sum is infinite.
+                if lane.iter().any(|x| x.is_nan()) {
+                    return A::nan();
+                }
+                return max;
+            }
+            max + lane.fold(A::zero(), |acc, &x| acc + (x - max).exp()).ln()
+        })
+    }
+
+    /// Return the number of non-zero elements along `axis`.
+    ///
+    /// Elements are compared to zero like in
+    /// [`.count_nonzero()`](ArrayBase::count_nonzero), so `-0.0` counts as zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[0, 2, 0],
+    ///                [4, 0, 6]]);
+    /// assert_eq!(a.count_nonzero_axis(Axis(0)), aview1(&[1, 1, 1]));
+    /// assert_eq!(a.count_nonzero_axis(Axis(1)), aview1(&[1, 2]));
+    /// ```
+    pub fn count_nonzero_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: Zero,
+        D: RemoveAxis,
+    {
+        self.fold_axis(axis, 0, |&count, elt| count + !elt.is_zero() as usize)
+    }
+
+    /// Return the cumulative sum of the elements along `axis`.
+    ///
+    /// The result has the same shape as `self`; each element is the sum of the elements
+    /// up to and including that position in its lane along `axis`. So an axis of length
+    /// zero or one gives an empty array or a copy, respectively.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.cumsum_axis(Axis(0)), arr2(&[[1, 2, 3],
+    ///                                          [5, 7, 9]]));
+    /// assert_eq!(a.cumsum_axis(Axis(1)), arr2(&[[1, 3, 6],
+    ///                                          [4, 9, 15]]));
+    /// ```
+    pub fn cumsum_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Add<Output = A>,
+    {
+        let mut res = self.to_owned();
+        res.cumsum_axis_inplace(axis);
+        res
+    }
+
+    /// Replace the elements with their cumulative sum along `axis`, in place.
+    ///
+    /// See [`.cumsum_axis()`](ArrayBase::cumsum_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn cumsum_axis_inplace(&mut self, axis: Axis)
+    where
+        A: Clone + Add<Output = A>,
+        S: DataMut,
+    {
+        self.accumulate_axis_inplace(axis, |prev, curr| *curr = prev.clone() + curr.clone());
+    }
+
+    /// Return the cumulative product of the elements along `axis`.
+    ///
+    /// The result has the same shape as `self`; each element is the product of the
+    /// elements up to and including that position in its lane along `axis`. So an axis
+    /// of length zero or one gives an empty array or a copy, respectively.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.cumprod_axis(Axis(0)), arr2(&[[1, 2, 3],
+    ///                                           [4, 10, 18]]));
+    /// assert_eq!(a.cumprod_axis(Axis(1)), arr2(&[[1, 2, 6],
+    ///                                           [4, 20, 120]]));
+    /// ```
+    pub fn cumprod_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Mul<Output = A>,
+    {
+        let mut res = self.to_owned();
+        res.accumulate_axis_inplace(axis, |prev, curr| *curr = prev.clone() * curr.clone());
+        res
+    }
+
+    /// Return the `n`-th order discrete difference of the elements along `axis`.
+    ///
+    /// The first order difference is `a[i + 1] - a[i]` along `axis`, and higher orders
+    /// are computed by taking differences repeatedly. Each order shortens `axis` by one,
+    /// so the result has the shape of `self` except that `axis` has length
+    /// `len_of(axis) - n`, or zero if `n` is at least the length of `axis`. For `n == 0`
+    /// the result is a copy of `self`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr1(&[1, 2, 4, 7, 0]);
+    /// assert_eq!(a.diff_axis(Axis(0), 1), arr1(&[1, 2, 3, -7]));
+    /// assert_eq!(a.diff_axis(Axis(0), 2), arr1(&[1, 1, -10]));
+    ///
+    /// let b = arr2(&[[1, 3, 6],
+    ///                [0, 5, 6]]);
+    /// assert_eq!(b.diff_axis(Axis(1), 1), arr2(&[[2, 3],
+    ///                                           [5, 1]]));
+    /// assert_eq!(b.diff_axis(Axis(0), 1), arr2(&[[-1, 2, 0]]));
+    /// ```
+    pub fn diff_axis(&self, axis: Axis, n: usize) -> Array<A, D>
+    where
+        A: Clone + Sub<Output = A>,
+    {
+        let diff = |a: ArrayView<'_, A, D>| {
+            let len = a.len_of(axis);
+            let lower = a.slice_axis(axis, Slice::from(..len.saturating_sub(1)));
+            let upper = a.slice_axis(axis, Slice::from(len.min(1)..));
+            Zip::from(&upper)
+                .and(&lower)
+                .map_collect(|u, l| u.clone() - l.clone())
+        };
+        if n == 0 {
+            return self.to_owned();
+        }
+        let mut res = diff(self.view());
+        // after len_of(axis) orders the axis is empty, and stays empty
+        for _ in 1..n.min(self.len_of(axis)) {
+            res = diff(res.view());
+        }
+        res
+    }
+
+    /// Return the element-wise absolute difference `|self - rhs|`.
+    ///
+    /// The smaller element is always subtracted from the larger one, so this never
+    /// underflows for unsigned integers. The result is NaN where either element is NaN.
+    ///
+    /// For signed integers the difference can still overflow, since it may not fit in the
+    /// element type (for example `127i8` and `-128i8`). As with the `-` operator, this
+    /// panics in debug builds and wraps around in release builds.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr0, arr1};
+    ///
+    /// let a = arr1(&[1.5, -2., 4.]);
+    /// assert_eq!(a.abs_diff(&arr1(&[2., 2., 1.])), arr1(&[0.5, 4., 3.]));
+    ///
+    /// let b = arr1(&[3u8, 250, 7]);
+    /// assert_eq!(b.abs_diff(&arr0(5)), arr1(&[2, 245, 2]));
+    /// ```
+    pub fn abs_diff<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+    where
+        A: Clone + PartialOrd + Sub<Output = A>,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).map_collect(|x, y| {
+            if x >= y {
+                x.clone() - y.clone()
+            } else {
+                y.clone() - x.clone()
+            }
+        })
+    }
 }
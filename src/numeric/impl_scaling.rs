@@ -0,0 +1,113 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+use crate::numeric::impl_summary::quantile_sorted;
+
+/// # Numerical Methods for Arrays
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a copy of the array, linearly rescaled so that its minimum and maximum
+    /// elements map to `new_min` and `new_max`.
+    ///
+    /// See [`.rescale()`](ArrayBase::rescale) for details.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[0., 5., 10.]);
+    /// assert_eq!(a.rescaled(0., 1.), arr1(&[0., 0.5, 1.]));
+    /// ```
+    pub fn rescaled(&self, new_min: A, new_max: A) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let mut res = self.to_owned();
+        res.rescale(new_min, new_max);
+        res
+    }
+
+    /// Rescale the elements of the array linearly, in place, so that its minimum and
+    /// maximum elements map to `new_min` and `new_max`.
+    ///
+    /// If all elements are equal (the range is zero), they are all set to `new_min`.
+    /// NaN elements are ignored when finding the minimum and maximum and remain NaN.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let mut a = arr1(&[2., 4., 3.]);
+    /// a.rescale(-1., 1.);
+    /// assert_eq!(a, arr1(&[-1., 1., 0.]));
+    /// ```
+    pub fn rescale(&mut self, new_min: A, new_max: A)
+    where
+        A: Float,
+        S: DataMut,
+    {
+        let (min, max) = self.fold((A::infinity(), A::neg_infinity()), |(min, max), &x| {
+            (min.min(x), max.max(x))
+        });
+        let range = max - min;
+        if range > A::zero() {
+            let new_range = new_max - new_min;
+            self.mapv_inplace(|x| new_min + (x - min) / range * new_range);
+        } else {
+            self.mapv_inplace(|x| if x.is_nan() { x } else { new_min });
+        }
+    }
+
+    /// Center each lane along `axis` on its median and scale it by its interquartile
+    /// range, in place.
+    ///
+    /// Each element `x` becomes `(x - median) / (q75 - q25)`, where the median and the
+    /// quartiles are those of its lane, computed by linear interpolation like in
+    /// [`.describe()`](ArrayBase::describe). This is robust to outliers, unlike scaling
+    /// by the standard deviation.
+    ///
+    /// Lanes with an interquartile range of zero are centered but not scaled. NaN elements
+    /// are ignored when computing the statistics and remain NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[1., 2., 3., 4., 100.]]);
+    /// a.robust_scale(Axis(1));
+    /// assert_eq!(a, arr2(&[[-1., -0.5, 0., 0.5, 48.5]]));
+    /// ```
+    pub fn robust_scale(&mut self, axis: Axis)
+    where
+        A: Float + FromPrimitive,
+        S: DataMut,
+    {
+        let mut sorted = Vec::with_capacity(self.len_of(axis));
+        for mut lane in self.lanes_mut(axis) {
+            sorted.clear();
+            sorted.extend(lane.iter().cloned().filter(|x| !x.is_nan()));
+            if sorted.is_empty() {
+                continue;
+            }
+            sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+            let median = quantile_sorted(&sorted, 0.5);
+            let iqr = quantile_sorted(&sorted, 0.75) - quantile_sorted(&sorted, 0.25);
+            if iqr > A::zero() {
+                lane.mapv_inplace(|x| (x - median) / iqr);
+            } else {
+                lane.mapv_inplace(|x| x - median);
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+
+/// Summary statistics of the elements of an array.
+///
+/// See [`.describe()`](ArrayBase::describe).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Summary<A> {
+    /// The number of elements.
+    pub count: usize,
+    /// The arithmetic mean.
+    pub mean: A,
+    /// The sample standard deviation (with `ddof = 1`).
+    pub std: A,
+    /// The minimum element.
+    pub min: A,
+    /// The 25th percentile.
+    pub q25: A,
+    /// The median, or 50th percentile.
+    pub median: A,
+    /// The 75th percentile.
+    pub q75: A,
+    /// The maximum element.
+    pub max: A,
+}
+
+/// # Numerical Methods for Arrays
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return summary statistics of all elements in the array: the number of elements,
+    /// the mean, the sample standard deviation, the minimum, the quartiles and the
+    /// maximum.
+    ///
+    /// Percentiles are computed by linear interpolation between the two closest
+    /// elements: with the elements sorted, the `q`-th quantile is at position `q * (n - 1)`.
+    ///
+    /// If the array is empty or contains a NaN, all the statistics except `count` are
+    /// NaN. The standard deviation of a single element is NaN.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let summary = arr1(&[4., 1., 3., 2., 5.]).describe();
+    /// assert_eq!(summary.count, 5);
+    /// assert_eq!(summary.mean, 3.);
+    /// assert_eq!((summary.min, summary.q25, summary.median, summary.q75, summary.max),
+    ///            (1., 2., 3., 4., 5.));
+    /// ```
+    pub fn describe(&self) -> Summary<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        let count = self.len();
+        let sorted = match sorted_elements(self) {
+            Some(v) if count > 0 => v,
+            _ => {
+                let nan = A::nan();
+                return Summary {
+                    count,
+                    mean: nan,
+                    std: nan,
+                    min: nan,
+                    q25: nan,
+                    median: nan,
+                    q75: nan,
+                    max: nan,
+                };
+            }
+        };
+        let std = if count > 1 { self.std(A::one()) } else { A::nan() };
+        Summary {
+            count,
+            mean: self.mean().unwrap(),
+            std,
+            min: sorted[0],
+            q25: quantile_sorted(&sorted, 0.25),
+            median: quantile_sorted(&sorted, 0.5),
+            q75: quantile_sorted(&sorted, 0.75),
+            max: sorted[count - 1],
+        }
+    }
+}
+
+/// Return the elements of `a` in ascending order, or `None` if any of them is NaN.
+fn sorted_elements<A, S, D>(a: &ArrayBase<S, D>) -> Option<Vec<A>>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut v = a.iter().cloned().collect::<Vec<_>>();
+    if v.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    // No NaNs, so the comparison is total
+    v.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    Some(v)
+}
+
+/// Return the `q`-th quantile of the non-empty sorted slice `sorted`, interpolating
+/// linearly.
+pub(crate) fn quantile_sorted<A>(sorted: &[A], q: f64) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    if lo == hi {
+        return sorted[lo];
+    }
+    let frac = A::from_f64(pos - lo as f64).unwrap();
+    sorted[lo] + (sorted[hi] - sorted[lo]) * frac
+}
+
+/// # Numerical Methods for Arrays
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    /// Clamp the elements of the array to the `lower_q`-th and `upper_q`-th quantiles of
+    /// all its elements, in place.
+    ///
+    /// Elements below the lower quantile are set to it, and elements above the upper
+    /// quantile are set to it. Quantiles are computed by linear interpolation like in
+    /// [`.describe()`](ArrayBase::describe). NaN elements are ignored when computing the
+    /// quantiles and are left unchanged.
+    ///
+    /// **Panics** if `lower_q` and `upper_q` do not satisfy `0 <= lower_q < upper_q <= 1`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let mut a = arr1(&[-100., 1., 2., 3., 4., 5., 6., 7., 8., 9., 100.]);
+    /// a.winsorize(0.1, 0.9);
+    /// assert_eq!(a, arr1(&[1., 1., 2., 3., 4., 5., 6., 7., 8., 9., 9.]));
+    /// ```
+    pub fn winsorize(&mut self, lower_q: f64, upper_q: f64)
+    where
+        A: Float + FromPrimitive,
+    {
+        assert!(
+            0. <= lower_q && lower_q < upper_q && upper_q <= 1.,
+            "ndarray: winsorize requires 0 <= lower_q < upper_q <= 1, got {} and {}",
+            lower_q,
+            upper_q
+        );
+        let mut sorted = self.iter().cloned().filter(|x| !x.is_nan()).collect::<Vec<_>>();
+        if sorted.is_empty() {
+            return;
+        }
+        sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let low = quantile_sorted(&sorted, lower_q);
+        let high = quantile_sorted(&sorted, upper_q);
+        self.mapv_inplace(|x| {
+            if x < low {
+                low
+            } else if x > high {
+                high
+            } else {
+                x
+            }
+        });
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Return the softmax cross-entropy loss of each sample in a batch.
+///
+/// `logits` has one row of unnormalized class scores per sample, and `targets` has the
+/// index of the correct class of each sample. The loss of sample `i` is
+/// `-ln(softmax(logits[i])[targets[i]])`, computed in the log domain as
+/// `logsumexp(logits[i]) - logits[i][targets[i]]` so that it does not overflow.
+///
+/// **Panics** if the number of targets is not the number of rows of `logits`, or if
+/// a target is not a valid column index.
+///
+/// ```
+/// use ndarray::{arr1, arr2, cross_entropy};
+///
+/// let logits = arr2(&[[0., 0.],
+///                     [1000., 0.]]);
+/// let loss = cross_entropy(&logits, &arr1(&[1, 0]));
+/// assert_eq!(loss, arr1(&[2f64.ln(), 0.]));
+/// ```
+pub fn cross_entropy<A, S, S2>(logits: &ArrayBase<S, Ix2>, targets: &ArrayBase<S2, Ix1>) -> Array1<A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = usize>,
+{
+    assert_eq!(
+        logits.nrows(),
+        targets.len(),
+        "ndarray: cross_entropy requires one target per row of logits"
+    );
+    let n_classes = logits.ncols();
+    let lse = logits.logsumexp_axis(Axis(1));
+    Zip::from(&lse)
+        .and(logits.rows())
+        .and(targets)
+        .map_collect(|&lse, row, &target| {
+            assert!(
+                target < n_classes,
+                "ndarray: cross_entropy target {} out of range for {} classes",
+                target,
+                n_classes
+            );
+            lse - row[target]
+        })
+}
+
+/// Return the softmax cross-entropy loss of each sample in a batch, with soft targets.
+///
+/// `logits` has one row of unnormalized class scores per sample, and `targets` has one
+/// row per sample with the target probability of each class. The targets of a sample
+/// should be non-negative and sum to one, but this is not checked. This allows for
+/// example label smoothing and the soft labels of knowledge distillation.
+///
+/// The loss of sample `i` is `-Σ_j targets[i][j] * ln(softmax(logits[i])[j])`, computed in
+/// the log domain so that it does not overflow. Classes with a target of zero don’t
+/// contribute to the loss. With one-hot targets, the result is the same as that of
+/// [`cross_entropy`] with the class indices.
+///
+/// **Panics** if `logits` and `targets` have different shapes.
+///
+/// ```
+/// use ndarray::{arr1, arr2, cross_entropy_soft};
+///
+/// let logits = arr2(&[[0., 0.],
+///                     [1000., 0.]]);
+/// let targets = arr2(&[[0.5, 0.5],
+///                      [1., 0.]]);
+/// let loss = cross_entropy_soft(&logits, &targets);
+/// assert_eq!(loss, arr1(&[2f64.ln(), 0.]));
+/// ```
+pub fn cross_entropy_soft<A, S, S2>(logits: &ArrayBase<S, Ix2>, targets: &ArrayBase<S2, Ix2>)
+    -> Array1<A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    assert_eq!(
+        logits.shape(),
+        targets.shape(),
+        "ndarray: cross_entropy_soft requires logits and targets of the same shape"
+    );
+    let lse = logits.logsumexp_axis(Axis(1));
+    Zip::from(&lse)
+        .and(logits.rows())
+        .and(targets.rows())
+        .map_collect(|&lse, row, target| {
+            Zip::from(&row).and(&target).fold(A::zero(), |acc, &x, &t| {
+                if t == A::zero() {
+                    acc
+                } else {
+                    acc + t * (lse - x)
+                }
+            })
+        })
+}
@@ -0,0 +1,393 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::{Array1, Zip};
+
+/// Count the pairs of true and predicted class labels of a classification.
+///
+/// Return a `num_classes × num_classes` matrix where element `[i, j]` is the number of
+/// samples with true class `i` that were predicted as class `j`. The diagonal counts
+/// the correct predictions.
+///
+/// **Panics** if `y_true` and `y_pred` have different lengths, or if a label is not
+/// less than `num_classes`.
+///
+/// ```
+/// use ndarray::{arr1, arr2, confusion_matrix};
+///
+/// let y_true = arr1(&[0, 1, 2, 2, 1]);
+/// let y_pred = arr1(&[0, 2, 2, 1, 1]);
+/// assert_eq!(
+///     confusion_matrix(&y_true, &y_pred, 3),
+///     arr2(&[[1, 0, 0],
+///            [0, 1, 1],
+///            [0, 1, 1]]));
+/// ```
+pub fn confusion_matrix<S, S2>(
+    y_true: &ArrayBase<S, Ix1>,
+    y_pred: &ArrayBase<S2, Ix1>,
+    num_classes: usize,
+) -> Array2<usize>
+where
+    S: Data<Elem = usize>,
+    S2: Data<Elem = usize>,
+{
+    assert_eq!(
+        y_true.len(),
+        y_pred.len(),
+        "ndarray: confusion_matrix requires labels of the same length, got {} and {}",
+        y_true.len(),
+        y_pred.len()
+    );
+    let mut res = Array2::zeros((num_classes, num_classes));
+    Zip::from(y_true).and(y_pred).for_each(|&t, &p| {
+        assert!(
+            t < num_classes && p < num_classes,
+            "ndarray: confusion_matrix label out of range for {} classes: ({}, {})",
+            num_classes,
+            t,
+            p
+        );
+        res[[t, p]] += 1;
+    });
+    res
+}
+
+/// Return the fraction of the predicted labels `y_pred` that equal the true labels
+/// `y_true`.
+///
+/// The accuracy is NaN if there are no labels.
+///
+/// **Panics** if `y_true` and `y_pred` have different lengths.
+///
+/// ```
+/// use ndarray::{accuracy, arr1};
+///
+/// let y_true = arr1(&[0, 1, 2, 2]);
+/// let y_pred = arr1(&[0, 2, 2, 2]);
+/// assert_eq!(accuracy(&y_true, &y_pred), 0.75);
+/// ```
+pub fn accuracy<A, S, S2>(y_true: &ArrayBase<S, Ix1>, y_pred: &ArrayBase<S2, Ix1>) -> f64
+where
+    A: PartialEq,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    assert_eq!(
+        y_true.len(),
+        y_pred.len(),
+        "ndarray: accuracy requires labels of the same length, got {} and {}",
+        y_true.len(),
+        y_pred.len()
+    );
+    let correct = Zip::from(y_true)
+        .and(y_pred)
+        .fold(0, |acc, t, p| acc + (t == p) as usize);
+    correct as f64 / y_true.len() as f64
+}
+
+/// Return the precision, the recall and the F1 score of each class, from a confusion
+/// matrix with the true classes along the rows, like the one returned by
+/// [`confusion_matrix`].
+///
+/// For class `i`, the precision is the fraction of the predictions of class `i` that
+/// are correct, `c[[i, i]] / c.column(i).sum()`, the recall is the fraction of the
+/// samples of class `i` that are predicted correctly, `c[[i, i]] / c.row(i).sum()`, and
+/// the F1 score is their harmonic mean. A metric is zero when its denominator is zero,
+/// for example the precision of a class that is never predicted.
+///
+/// The per-class metrics can be averaged in different ways:
+///
+/// - The *macro* average is the unweighted mean over the classes, for example
+///   `precision.mean()`, which treats all classes as equally important.
+/// - The *weighted* average weights each class by its number of samples, the row sums
+///   of the confusion matrix.
+/// - The *micro* average computes the metric from the total counts over all classes.
+///   For single-label classification, the micro averaged precision, recall and F1 score
+///   all equal the [`accuracy`].
+///
+/// **Panics** if `confusion` is not square.
+///
+/// ```
+/// use ndarray::{arr1, arr2, precision_recall_f1};
+///
+/// let confusion = arr2(&[[3, 1],
+///                        [0, 4]]);
+/// let (precision, recall, f1) = precision_recall_f1(&confusion);
+/// assert_eq!(precision, arr1(&[1., 0.8]));
+/// assert_eq!(recall, arr1(&[0.75, 1.]));
+/// assert_eq!(f1.mapv(|x| (x * 1000.).round()), arr1(&[857., 889.]));
+///
+/// // macro average
+/// assert_eq!(precision.mean(), Some(0.9));
+/// ```
+pub fn precision_recall_f1<S>(
+    confusion: &ArrayBase<S, Ix2>,
+) -> (Array1<f64>, Array1<f64>, Array1<f64>)
+where
+    S: Data<Elem = usize>,
+{
+    assert!(
+        confusion.is_square(),
+        "ndarray: precision_recall_f1 requires a square confusion matrix, got shape {:?}",
+        confusion.shape()
+    );
+    let ratio = |x: usize, total: usize| if total == 0 { 0. } else { x as f64 / total as f64 };
+    let predicted = confusion.sum_axis(Axis(0));
+    let actual = confusion.sum_axis(Axis(1));
+    let correct = confusion.diag();
+    let precision = Zip::from(&correct)
+        .and(&predicted)
+        .map_collect(|&c, &p| ratio(c, p));
+    let recall = Zip::from(&correct).and(&actual).map_collect(|&c, &a| ratio(c, a));
+    let f1 = Zip::from(&precision).and(&recall).map_collect(|&p, &r| {
+        if p + r == 0. {
+            0.
+        } else {
+            2. * p * r / (p + r)
+        }
+    });
+    (precision, recall, f1)
+}
+
+/// Return the receiver operating characteristic (ROC) curve of a binary classifier, from
+/// the `scores` it assigned to samples and their true `labels`.
+///
+/// Return the false positive rates, the true positive rates and the decreasing
+/// thresholds of the curve. Point `i` of the curve is the result of predicting the
+/// samples with scores of at least `thresholds[i]` as positive. The thresholds are the
+/// distinct scores in decreasing order, preceded by infinity for the point `(0, 0)`
+/// where no sample is predicted as positive, so the curve ends at `(1, 1)`.
+///
+/// The true positive rates are NaN if there are no positive labels, and the false
+/// positive rates are NaN if there are no negative labels.
+///
+/// **Panics** if `scores` and `labels` have different lengths, or if a score is NaN.
+///
+/// ```
+/// use ndarray::{arr1, auc, roc_curve};
+///
+/// let scores = arr1(&[0.1, 0.4, 0.35, 0.8]);
+/// let labels = arr1(&[false, false, true, true]);
+/// let (fpr, tpr, thresholds) = roc_curve(&scores, &labels);
+/// assert_eq!(fpr, arr1(&[0., 0., 0.5, 0.5, 1.]));
+/// assert_eq!(tpr, arr1(&[0., 0.5, 0.5, 1., 1.]));
+/// assert_eq!(thresholds, arr1(&[f64::INFINITY, 0.8, 0.4, 0.35, 0.1]));
+/// assert_eq!(auc(&fpr, &tpr), 0.75);
+/// ```
+pub fn roc_curve<S, S2>(
+    scores: &ArrayBase<S, Ix1>,
+    labels: &ArrayBase<S2, Ix1>,
+) -> (Array1<f64>, Array1<f64>, Array1<f64>)
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = bool>,
+{
+    assert_eq!(
+        scores.len(),
+        labels.len(),
+        "ndarray: roc_curve requires scores and labels of the same length, got {} and {}",
+        scores.len(),
+        labels.len()
+    );
+    assert!(
+        !scores.iter().any(|x| x.is_nan()),
+        "ndarray: roc_curve requires scores that are not NaN"
+    );
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&i, &j| scores[j].partial_cmp(&scores[i]).unwrap());
+
+    let mut true_pos = vec![0];
+    let mut false_pos = vec![0];
+    let mut thresholds = vec![f64::INFINITY];
+    let (mut tp, mut fp) = (0, 0);
+    for (k, &i) in order.iter().enumerate() {
+        if labels[i] {
+            tp += 1;
+        } else {
+            fp += 1;
+        }
+        // emit a point after the last sample of each distinct score
+        if order.get(k + 1).filter(|&&next| scores[next] == scores[i]).is_none() {
+            true_pos.push(tp);
+            false_pos.push(fp);
+            thresholds.push(scores[i]);
+        }
+    }
+    let rates = |counts: Vec<usize>, total: usize| {
+        Array1::from(counts).mapv(|c| c as f64 / total as f64)
+    };
+    (rates(false_pos, fp), rates(true_pos, tp), Array1::from(thresholds))
+}
+
+/// Return the area under the curve through the points `(x[i], y[i])`, computed with the
+/// trapezoidal rule.
+///
+/// The points are taken in order, so `x` should be monotonic, like the false positive
+/// rates of a [`roc_curve`]; the area is negative if `x` is decreasing. The area is zero
+/// if there are less than two points.
+///
+/// **Panics** if `x` and `y` have different lengths.
+///
+/// ```
+/// use ndarray::{arr1, auc};
+///
+/// assert_eq!(auc(&arr1(&[0., 0.5, 1.]), &arr1(&[0., 1., 1.])), 0.75);
+/// ```
+pub fn auc<S, S2>(x: &ArrayBase<S, Ix1>, y: &ArrayBase<S2, Ix1>) -> f64
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+{
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "ndarray: auc requires x and y of the same length, got {} and {}",
+        x.len(),
+        y.len()
+    );
+    if x.len() < 2 {
+        return 0.;
+    }
+    let (x0, x1) = (x.slice(s![..-1]), x.slice(s![1..]));
+    let (y0, y1) = (y.slice(s![..-1]), y.slice(s![1..]));
+    Zip::from(&x0)
+        .and(&x1)
+        .and(&y0)
+        .and(&y1)
+        .fold(0., |acc, &xa, &xb, &ya, &yb| acc + (xb - xa) * (ya + yb) / 2.)
+}
+
+/// Return the mean squared error between the targets `y_true` and the predictions
+/// `y_pred`, over all their elements.
+///
+/// The result is NaN if the arrays are empty.
+///
+/// **Panics** if the shapes of `y_true` and `y_pred` are different.
+///
+/// ```
+/// use ndarray::{arr1, mse};
+///
+/// assert_eq!(mse(&arr1(&[1., 2., 3.]), &arr1(&[1., 4., 2.])), 5. / 3.);
+/// ```
+pub fn mse<S, S2, D>(y_true: &ArrayBase<S, D>, y_pred: &ArrayBase<S2, D>) -> f64
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    D: Dimension,
+{
+    sum_of_errors(y_true, y_pred, |e| e * e) / y_true.len() as f64
+}
+
+/// Return the root mean squared error between the targets `y_true` and the predictions
+/// `y_pred`, over all their elements; the square root of [`mse`].
+///
+/// The result is NaN if the arrays are empty.
+///
+/// **Panics** if the shapes of `y_true` and `y_pred` are different.
+///
+/// ```
+/// use ndarray::{arr1, rmse};
+///
+/// assert_eq!(rmse(&arr1(&[1., 2., 3., 4.]), &arr1(&[2., 1., 4., 3.])), 1.);
+/// ```
+#[cfg(feature = "std")]
+pub fn rmse<S, S2, D>(y_true: &ArrayBase<S, D>, y_pred: &ArrayBase<S2, D>) -> f64
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    D: Dimension,
+{
+    mse(y_true, y_pred).sqrt()
+}
+
+/// Return the mean absolute error between the targets `y_true` and the predictions
+/// `y_pred`, over all their elements.
+///
+/// The result is NaN if the arrays are empty.
+///
+/// **Panics** if the shapes of `y_true` and `y_pred` are different.
+///
+/// ```
+/// use ndarray::{arr1, mae};
+///
+/// assert_eq!(mae(&arr1(&[1., 2., 3.]), &arr1(&[1., 4., 2.])), 1.);
+/// ```
+pub fn mae<S, S2, D>(y_true: &ArrayBase<S, D>, y_pred: &ArrayBase<S2, D>) -> f64
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    D: Dimension,
+{
+    sum_of_errors(y_true, y_pred, |e| if e < 0. { -e } else { e }) / y_true.len() as f64
+}
+
+/// Return the coefficient of determination R² of the predictions `y_pred` of the targets
+/// `y_true`, over all their elements.
+///
+/// R² is `1 - SS_res / SS_tot`, where `SS_res` is the sum of the squared errors and
+/// `SS_tot` is the sum of the squared differences between the targets and their mean.
+/// It is 1 for perfect predictions, 0 for always predicting the mean of the targets, and
+/// negative for worse predictions.
+///
+/// If the targets are constant, so that `SS_tot` is zero, R² is not defined; then the
+/// result is 1 if the predictions are perfect and 0 otherwise (like scikit-learn). The
+/// result is NaN if the arrays are empty.
+///
+/// **Panics** if the shapes of `y_true` and `y_pred` are different.
+///
+/// ```
+/// use ndarray::{arr1, r2_score};
+///
+/// let y_true = arr1(&[1., 2., 3., 4.]);
+/// assert_eq!(r2_score(&y_true, &arr1(&[1., 2., 3., 4.])), 1.);
+/// assert_eq!(r2_score(&y_true, &arr1(&[2.5, 2.5, 2.5, 2.5])), 0.);
+/// assert_eq!(r2_score(&y_true, &arr1(&[2., 1., 3.5, 3.5])), 0.5);
+/// ```
+pub fn r2_score<S, S2, D>(y_true: &ArrayBase<S, D>, y_pred: &ArrayBase<S2, D>) -> f64
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    D: Dimension,
+{
+    let ss_res = sum_of_errors(y_true, y_pred, |e| e * e);
+    let mean = y_true.sum() / y_true.len() as f64;
+    let ss_tot = y_true.fold(0., |acc, &y| acc + (y - mean) * (y - mean));
+    if y_true.is_empty() {
+        f64::NAN
+    } else if ss_tot == 0. {
+        if ss_res == 0. {
+            1.
+        } else {
+            0.
+        }
+    } else {
+        1. - ss_res / ss_tot
+    }
+}
+
+fn sum_of_errors<S, S2, D, F>(y_true: &ArrayBase<S, D>, y_pred: &ArrayBase<S2, D>, f: F) -> f64
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    D: Dimension,
+    F: Fn(f64) -> f64,
+{
+    assert_eq!(
+        y_true.shape(),
+        y_pred.shape(),
+        "ndarray: regression metrics require targets and predictions of the same shape"
+    );
+    Zip::from(y_true)
+        .and(y_pred)
+        .fold(0., |acc, &t, &p| acc + f(t - p))
+}
@@ -1 +1,34 @@
 mod impl_numeric;
+mod impl_bool;
+mod impl_integer;
+#[cfg(feature = "std")]
+mod impl_summary;
+#[cfg(feature = "std")]
+mod impl_scaling;
+#[cfg(feature = "std")]
+mod impl_float_maths;
+#[cfg(feature = "std")]
+mod distance;
+#[cfg(feature = "std")]
+mod loss;
+#[cfg(feature = "std")]
+mod scaler;
+mod polynomial;
+mod metrics;
+
+#[cfg(feature = "std")]
+pub use self::impl_summary::Summary;
+#[cfg(feature = "std")]
+pub use self::distance::{cdist, Metric};
+#[cfg(feature = "std")]
+pub use self::loss::{cross_entropy, cross_entropy_soft};
+#[cfg(feature = "std")]
+pub use self::scaler::{MinMaxScaler, StandardScaler};
+#[cfg(feature = "std")]
+pub use self::polynomial::polyfit;
+pub use self::polynomial::polyval;
+pub use self::metrics::{
+    accuracy, auc, confusion_matrix, mae, mse, precision_recall_f1, r2_score, roc_curve,
+};
+#[cfg(feature = "std")]
+pub use self::metrics::rmse;
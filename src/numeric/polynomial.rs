@@ -0,0 +1,179 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+use std::ops::{Add, Mul};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Fit a polynomial of degree `degree` to the points `(x[i], y[i])` by least squares.
+///
+/// Return the coefficients of the polynomial, highest degree first (like NumPy’s
+/// `polyfit`). The least squares problem for the Vandermonde matrix of `x` is solved
+/// using a QR decomposition.
+///
+/// If the points do not determine a unique polynomial, for example because too many
+/// of the `x` values are equal, some of the coefficients are NaN or infinite.
+///
+/// **Panics** if `x` and `y` have different lengths, or if there are fewer than
+/// `degree + 1` points.
+///
+/// ```
+/// use ndarray::{arr1, polyfit};
+///
+/// // y = 2x² - 3x + 1
+/// let x = arr1(&[0., 1., 2., 3.]);
+/// let y = x.mapv(|x| 2. * x * x - 3. * x + 1.);
+/// let coeffs = polyfit(&x, &y, 2);
+/// for (c, e) in coeffs.iter().zip(&[2., -3., 1.]) {
+///     assert!((c - e).abs() < 1e-10);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn polyfit<S, S2>(x: &ArrayBase<S, Ix1>, y: &ArrayBase<S2, Ix1>, degree: usize) -> Array1<f64>
+where
+    S: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+{
+    let m = x.len();
+    let n = degree + 1;
+    assert_eq!(m, y.len(), "ndarray: polyfit requires `x` and `y` of the same length");
+    assert!(m >= n, "ndarray: polyfit of degree {} requires at least {} points, got {}",
+            degree, n, m);
+
+    // Vandermonde matrix, highest power first
+    let mut a = Array2::from_shape_fn((m, n), |(i, j)| x[i].powi((degree - j) as i32));
+    let mut b = y.to_owned();
+
+    // Householder QR, applying the reflections to `b` as we go
+    for k in 0..n {
+        let norm = a.slice(s![k.., k]).fold(0., |acc, &v| acc + v * v).sqrt();
+        if norm == 0. {
+            continue;
+        }
+        let alpha = if a[(k, k)] > 0. { -norm } else { norm };
+        let mut v = a.slice(s![k.., k]).to_owned();
+        v[0] -= alpha;
+        let v_norm2 = v.dot(&v);
+        if v_norm2 == 0. {
+            continue;
+        }
+        for j in k..n {
+            let mut col = a.slice_mut(s![k.., j]);
+            let f = 2. * v.dot(&col) / v_norm2;
+            col.scaled_add(-f, &v);
+        }
+        let mut rhs = b.slice_mut(s![k..]);
+        let f = 2. * v.dot(&rhs) / v_norm2;
+        rhs.scaled_add(-f, &v);
+    }
+
+    // Back substitution with the upper triangular R
+    let mut coeffs = Array1::zeros(n);
+    for k in (0..n).rev() {
+        let mut acc = b[k];
+        Zip::from(a.slice(s![k, k + 1..]))
+            .and(coeffs.slice(s![k + 1..]))
+            .for_each(|&r, &c| acc -= r * c);
+        coeffs[k] = acc / a[(k, k)];
+    }
+    coeffs
+}
+
+/// Evaluate the polynomial with coefficients `coeffs` at each element of `x`.
+///
+/// The coefficients are given highest degree first (like NumPy’s `polyval`), so that
+/// the result is `coeffs[0] * x^(n-1) + ... + coeffs[n-1]`; it is computed with Horner’s
+/// method. If `coeffs` is empty, the result is all zeros.
+///
+/// Return an array with the same shape as `x`.
+///
+/// ```
+/// use ndarray::{arr1, arr2, polyval};
+///
+/// // x² - 1
+/// let p = arr1(&[1, 0, -1]);
+/// assert_eq!(polyval(&p, &arr1(&[0, 1, 2, 3])), arr1(&[-1, 0, 3, 8]));
+/// assert_eq!(polyval(&p, &arr2(&[[-2, -1], [4, 5]])), arr2(&[[3, 0], [15, 24]]));
+/// ```
+pub fn polyval<A, S, S2, D>(coeffs: &ArrayBase<S, Ix1>, x: &ArrayBase<S2, D>) -> Array<A, D>
+where
+    A: Clone + Zero + Add<Output = A> + Mul<Output = A>,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    x.map(|x| {
+        coeffs
+            .iter()
+            .fold(A::zero(), |acc, c| acc * x.clone() + c.clone())
+    })
+}
+
+/// # Polynomial Methods for 2-D Arrays
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the polynomial features of the rows of a feature matrix: all products of
+    /// the features (columns) up to degree `degree`, including cross terms, like
+    /// scikit-learn’s `PolynomialFeatures`.
+    ///
+    /// The columns of the result are ordered by degree. Within a degree, the products are
+    /// ordered lexicographically by the indices of their features, so for features
+    /// `a, b` and `degree == 2` the columns are `[1, a, b, a², ab, b²]`. The bias column
+    /// of ones is only present if `include_bias` is true.
+    ///
+    /// For `n` features, the number of columns is the binomial coefficient
+    /// `(n + degree)! / (n! degree!)`, minus one without the bias column.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let x = arr2(&[[2., 3.],
+    ///                [-1., 4.]]);
+    /// assert_eq!(
+    ///     x.polynomial_features(2, true),
+    ///     arr2(&[[1., 2., 3., 4., 6., 9.],
+    ///            [1., -1., 4., 1., -4., 16.]]));
+    /// ```
+    pub fn polynomial_features(&self, degree: usize, include_bias: bool) -> Array2<A>
+    where
+        A: Clone + One + Mul<Output = A>,
+    {
+        let n = self.ncols();
+        let mut columns = Vec::new();
+        if include_bias {
+            columns.push(Array1::ones(self.nrows()));
+        }
+        // The terms of the previous degree, as (index of the last feature, column)
+        let mut terms = Vec::new();
+        if degree >= 1 {
+            for k in 0..n {
+                terms.push((k, columns.len()));
+                columns.push(self.column(k).to_owned());
+            }
+        }
+        for _ in 1..degree {
+            let mut next_terms = Vec::new();
+            for &(last, c) in &terms {
+                for k in last..n {
+                    let column = Zip::from(&columns[c])
+                        .and(self.column(k))
+                        .map_collect(|x, y| x.clone() * y.clone());
+                    next_terms.push((k, columns.len()));
+                    columns.push(column);
+                }
+            }
+            terms = next_terms;
+        }
+        Array2::from_shape_fn((self.nrows(), columns.len()), |(i, j)| columns[j][i].clone())
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{Array1, Array2, ArrayView1, ArrayView2, Zip};
+
+/// Standardize the columns of a matrix with statistics fitted on other data.
+///
+/// [`.fit()`](StandardScaler::fit) computes the mean and the (population) standard
+/// deviation of each column of a matrix of samples, one sample per row, and stores
+/// them. [`.transform()`](StandardScaler::transform) then maps each element `x` of a
+/// column to `(x - mean) / std`, using the stored parameters, so that statistics from
+/// training data can be applied to test data.
+///
+/// Columns with a standard deviation of zero are centered but not scaled.
+///
+/// ```
+/// use ndarray::{array, StandardScaler};
+///
+/// let train = array![[1., 10.],
+///                    [3., 10.]];
+/// let mut scaler = StandardScaler::new();
+/// scaler.fit(&train.view());
+/// assert_eq!(scaler.mean().unwrap(), array![2., 10.]);
+/// assert_eq!(scaler.std().unwrap(), array![1., 0.]);
+///
+/// let test = array![[4., 12.]];
+/// let scaled = scaler.transform(&test.view());
+/// assert_eq!(scaled, array![[2., 2.]]);
+/// assert_eq!(scaler.inverse_transform(&scaled.view()), test);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StandardScaler {
+    params: Option<(Array1<f64>, Array1<f64>)>,
+}
+
+impl StandardScaler {
+    /// Create a scaler that is not fitted yet.
+    pub fn new() -> Self {
+        StandardScaler { params: None }
+    }
+
+    /// Compute and store the mean and standard deviation of each column of `data`,
+    /// replacing any previously fitted parameters.
+    ///
+    /// **Panics** if `data` has no rows.
+    pub fn fit(&mut self, data: &ArrayView2<f64>) {
+        let mean = data
+            .mean_axis(Axis(0))
+            .expect("ndarray: StandardScaler::fit requires at least one row");
+        let std = data.std_axis(Axis(0), 0.);
+        self.params = Some((mean, std));
+    }
+
+    /// Fit the scaler on `data` and return `data` standardized with the fitted parameters.
+    ///
+    /// **Panics** if `data` has no rows.
+    pub fn fit_transform(&mut self, data: &ArrayView2<f64>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// Return `data` standardized with the fitted parameters.
+    ///
+    /// **Panics** if the scaler is not fitted, or if the number of columns of `data`
+    /// does not match the data it was fitted on.
+    pub fn transform(&self, data: &ArrayView2<f64>) -> Array2<f64> {
+        let (mean, std) = self.fitted_params(data, "transform");
+        let mut res = data - mean;
+        Zip::from(res.columns_mut()).and(std).for_each(|mut col, &s| {
+            if s > 0. {
+                col /= s;
+            }
+        });
+        res
+    }
+
+    /// Return `data` mapped back from the standardized scale to the original scale of
+    /// the fitted data; the inverse of [`.transform()`](StandardScaler::transform).
+    ///
+    /// **Panics** if the scaler is not fitted, or if the number of columns of `data`
+    /// does not match the data it was fitted on.
+    pub fn inverse_transform(&self, data: &ArrayView2<f64>) -> Array2<f64> {
+        let (mean, std) = self.fitted_params(data, "inverse_transform");
+        let mut res = data.to_owned();
+        Zip::from(res.columns_mut()).and(std).for_each(|mut col, &s| {
+            if s > 0. {
+                col *= s;
+            }
+        });
+        res += mean;
+        res
+    }
+
+    /// Return the fitted mean of each column, or `None` if the scaler is not fitted.
+    pub fn mean(&self) -> Option<ArrayView1<'_, f64>> {
+        self.params.as_ref().map(|(mean, _)| mean.view())
+    }
+
+    /// Return the fitted standard deviation of each column, or `None` if the scaler is
+    /// not fitted.
+    pub fn std(&self) -> Option<ArrayView1<'_, f64>> {
+        self.params.as_ref().map(|(_, std)| std.view())
+    }
+
+    fn fitted_params(&self, data: &ArrayView2<f64>, method: &str)
+        -> (&Array1<f64>, &Array1<f64>)
+    {
+        let (mean, std) = match &self.params {
+            Some((mean, std)) => (mean, std),
+            None => panic!("ndarray: StandardScaler::{} called before fit", method),
+        };
+        assert_eq!(
+            data.ncols(),
+            mean.len(),
+            "ndarray: StandardScaler::{} expects {} columns, got {}",
+            method,
+            mean.len(),
+            data.ncols()
+        );
+        (mean, std)
+    }
+}
+
+/// Rescale the columns of a matrix to a range, with the extremes fitted on other data.
+///
+/// [`.fit()`](MinMaxScaler::fit) finds the minimum and the maximum of each column of a
+/// matrix of samples, one sample per row, and stores them.
+/// [`.transform()`](MinMaxScaler::transform) then maps each column linearly so that the
+/// fitted minimum and maximum become `feature_min` and `feature_max`.
+///
+/// Values outside of the fitted range extrapolate linearly to values outside of
+/// `[feature_min, feature_max]`, unless clipping is enabled with
+/// [`.clip()`](MinMaxScaler::clip). Columns where the fitted minimum and maximum are
+/// equal are mapped to `feature_min`. NaN elements are ignored when fitting and remain
+/// NaN.
+///
+/// ```
+/// use ndarray::{array, MinMaxScaler};
+///
+/// let train = array![[0., 10.],
+///                    [4., 20.]];
+/// let mut scaler = MinMaxScaler::new(0., 1.);
+/// scaler.fit(&train.view());
+///
+/// let test = array![[2., 25.]];
+/// assert_eq!(scaler.transform(&test.view()), array![[0.5, 1.5]]);
+///
+/// let scaler = scaler.clip(true);
+/// assert_eq!(scaler.transform(&test.view()), array![[0.5, 1.]]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinMaxScaler {
+    feature_min: f64,
+    feature_max: f64,
+    clip: bool,
+    params: Option<(Array1<f64>, Array1<f64>)>,
+}
+
+impl MinMaxScaler {
+    /// Create a scaler, that is not fitted yet, mapping to the range
+    /// `[feature_min, feature_max]`.
+    ///
+    /// **Panics** if `feature_min` is not less than `feature_max`.
+    pub fn new(feature_min: f64, feature_max: f64) -> Self {
+        assert!(
+            feature_min < feature_max,
+            "ndarray: MinMaxScaler requires feature_min < feature_max, got [{}, {}]",
+            feature_min,
+            feature_max
+        );
+        MinMaxScaler {
+            feature_min,
+            feature_max,
+            clip: false,
+            params: None,
+        }
+    }
+
+    /// Set whether [`.transform()`](MinMaxScaler::transform) clips its results to
+    /// `[feature_min, feature_max]` (disabled by default).
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Compute and store the minimum and maximum of each column of `data`, replacing
+    /// any previously fitted parameters.
+    ///
+    /// **Panics** if `data` has no rows.
+    pub fn fit(&mut self, data: &ArrayView2<f64>) {
+        assert!(
+            data.nrows() > 0,
+            "ndarray: MinMaxScaler::fit requires at least one row"
+        );
+        let data_min = data.fold_axis(Axis(0), f64::INFINITY, |&min, &x| min.min(x));
+        let data_max = data.fold_axis(Axis(0), f64::NEG_INFINITY, |&max, &x| max.max(x));
+        self.params = Some((data_min, data_max));
+    }
+
+    /// Fit the scaler on `data` and return `data` rescaled with the fitted parameters.
+    ///
+    /// **Panics** if `data` has no rows.
+    pub fn fit_transform(&mut self, data: &ArrayView2<f64>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// Return `data` rescaled with the fitted parameters.
+    ///
+    /// **Panics** if the scaler is not fitted, or if the number of columns of `data`
+    /// does not match the data it was fitted on.
+    pub fn transform(&self, data: &ArrayView2<f64>) -> Array2<f64> {
+        let (data_min, data_max) = self.fitted_params(data, "transform");
+        let (feature_min, feature_max) = (self.feature_min, self.feature_max);
+        let feature_range = feature_max - feature_min;
+        let mut res = data.to_owned();
+        Zip::from(res.columns_mut())
+            .and(data_min)
+            .and(data_max)
+            .for_each(|mut col, &min, &max| {
+                let range = max - min;
+                col.mapv_inplace(|x| {
+                    if x.is_nan() {
+                        return x;
+                    }
+                    let y = if range > 0. {
+                        feature_min + (x - min) / range * feature_range
+                    } else {
+                        feature_min
+                    };
+                    if self.clip {
+                        y.max(feature_min).min(feature_max)
+                    } else {
+                        y
+                    }
+                });
+            });
+        res
+    }
+
+    /// Return `data` mapped back from the feature range to the original scale of the
+    /// fitted data; the inverse of [`.transform()`](MinMaxScaler::transform) for values
+    /// that were not clipped.
+    ///
+    /// Columns where the fitted minimum and maximum are equal are mapped to that value.
+    ///
+    /// **Panics** if the scaler is not fitted, or if the number of columns of `data`
+    /// does not match the data it was fitted on.
+    pub fn inverse_transform(&self, data: &ArrayView2<f64>) -> Array2<f64> {
+        let (data_min, data_max) = self.fitted_params(data, "inverse_transform");
+        let feature_min = self.feature_min;
+        let feature_range = self.feature_max - feature_min;
+        let mut res = data.to_owned();
+        Zip::from(res.columns_mut())
+            .and(data_min)
+            .and(data_max)
+            .for_each(|mut col, &min, &max| {
+                let range = max - min;
+                col.mapv_inplace(|y| {
+                    if y.is_nan() {
+                        y
+                    } else {
+                        min + (y - feature_min) / feature_range * range
+                    }
+                });
+            });
+        res
+    }
+
+    /// Return the fitted minimum of each column, or `None` if the scaler is not fitted.
+    pub fn data_min(&self) -> Option<ArrayView1<'_, f64>> {
+        self.params.as_ref().map(|(min, _)| min.view())
+    }
+
+    /// Return the fitted maximum of each column, or `None` if the scaler is not fitted.
+    pub fn data_max(&self) -> Option<ArrayView1<'_, f64>> {
+        self.params.as_ref().map(|(_, max)| max.view())
+    }
+
+    fn fitted_params(&self, data: &ArrayView2<f64>, method: &str)
+        -> (&Array1<f64>, &Array1<f64>)
+    {
+        let (min, max) = match &self.params {
+            Some((min, max)) => (min, max),
+            None => panic!("ndarray: MinMaxScaler::{} called before fit", method),
+        };
+        assert_eq!(
+            data.ncols(),
+            min.len(),
+            "ndarray: MinMaxScaler::{} expects {} columns, got {}",
+            method,
+            min.len(),
+            data.ncols()
+        );
+        (min, max)
+    }
+}
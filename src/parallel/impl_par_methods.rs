@@ -1,5 +1,7 @@
-use crate::{Array, ArrayBase, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{Array, ArrayBase, ArrayViewMut, Data, DataMut, Dimension, IntoDimension, IntoNdProducer, NdProducer, Zip};
 use crate::AssignElem;
+use crate::low_level_util::AbortIfPanic;
+use std::mem::MaybeUninit;
 
 use crate::parallel::prelude::*;
 use crate::parallel::par::ParallelSplits;
@@ -43,8 +45,114 @@ where
             .into_par_iter()
             .for_each(move |x| *x = f(x.clone()))
     }
+
+    /// Parallel version of `exact_chunks_mut`.
+    ///
+    /// Return a parallel iterator over the whole, non-overlapping chunks of size
+    /// `chunk_size`, skipping the remainder along each dimension that doesn't fit evenly.
+    ///
+    /// Chunks are visited in arbitrary order.
+    ///
+    /// **Panics** if any dimension of `chunk_size` is zero<br>
+    /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
+    /// number of array axes.)
+    pub fn par_exact_chunks_mut<'a, E>(&'a mut self, chunk_size: E)
+        -> impl ParallelIterator<Item = ArrayViewMut<'a, A, D>> + 'a
+    where
+        E: IntoDimension<Dim = D>,
+        A: 'a,
+    {
+        Zip::from(self.exact_chunks_mut(chunk_size))
+            .into_par_iter()
+            .map(|(chunk,)| chunk)
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: Sync,
+{
+    /// Parallel version of `mapv`.
+    ///
+    /// Call `f` by **v**alue on each element and create a new array with the new values.
+    ///
+    /// Elements are visited in arbitrary order, but each result is placed at the position
+    /// of its input element.
+    ///
+    /// Return an array with the same shape as `self`, in standard layout.
+    pub fn par_mapv<B, F>(&self, f: F) -> Array<B, D>
+    where
+        F: Fn(A) -> B + Sync + Send,
+        A: Clone,
+        B: Send,
+    {
+        let mut output = Array::uninit(self.raw_dim());
+        Zip::from(self).par_map_assign_into(&mut output, move |x| f(x.clone()));
+        unsafe { output.assume_init() }
+    }
 }
 
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, D> Array<A, D>
+where
+    D: Dimension,
+    A: Send,
+{
+    /// Parallel version of `move_into`.
+    ///
+    /// Move all elements from self into `new_array`, which must be of the same shape but
+    /// can have a different memory layout. The destination is overwritten completely.
+    ///
+    /// The elements are moved in parallel, and like with `move_into`, the elements owned
+    /// by `self` that are not part of its active view are dropped afterwards, on the
+    /// calling thread. Arrays with fewer than 2<sup>14</sup> elements are moved serially.
+    ///
+    /// ***Panics*** if the shapes don't agree.
+    pub fn par_move_into<'a, AM>(self, new_array: AM)
+    where
+        AM: Into<ArrayViewMut<'a, MaybeUninit<A>, D>>,
+        A: 'a,
+    {
+        let new_array = new_array.into();
+        if self.len() < MOVE_INTO_MIN_PAR_LEN {
+            return self.move_into(new_array);
+        }
+        self.par_move_into_impl(new_array)
+    }
+
+    fn par_move_into_impl(mut self, new_array: ArrayViewMut<MaybeUninit<A>, D>) {
+        unsafe {
+            // Check the shapes before any element is moved
+            let splits = ParallelSplits {
+                iter: Zip::from(SendProducer::new(self.raw_view_mut())).and(new_array),
+                max_splits: COLLECT_MAX_SPLITS,
+            };
+            // Safety: copy_to_nonoverlapping cannot panic
+            let guard = AbortIfPanic(&"par_move_into: moving out of owned value");
+            // Move all reachable elements. Each chunk of the zip is moved by one thread,
+            // and the chunks don't overlap in either array.
+            splits.for_each(|zip| {
+                zip.for_each(|src, dst| {
+                    src.copy_to_nonoverlapping(dst.as_mut_ptr(), 1);
+                })
+            });
+            guard.defuse();
+            // Drop all unreachable elements
+            self.drop_unreachable_elements();
+        }
+    }
+}
+
+/// Arrays with fewer elements are moved serially by `par_move_into`
+const MOVE_INTO_MIN_PAR_LEN: usize = 1 << 14;
+
 // Zip
 
 const COLLECT_MAX_SPLITS: usize = 10;
@@ -84,6 +192,47 @@ macro_rules! zip_impl {
                 self.into_par_iter().for_each(move |($($p,)*)| function($($p),*))
             }
 
+            /// Parallel version of `fold`.
+            ///
+            /// The Zip is split into chunks, which are split in the way that best preserves
+            /// element locality. Each chunk is folded serially with `fold`, starting from a
+            /// value created by `identity`, and the partial results of the chunks are
+            /// combined with `reduce`, like rayon's `fold` followed by `reduce`.
+            ///
+            /// `reduce` must be associative, and `identity()` must be an identity for it,
+            /// since the number of chunks and the order in which their results are combined
+            /// are unspecified. For floating point numbers, the result may therefore differ
+            /// slightly from the result of the serial `fold`.
+            ///
+            /// Requires crate feature `rayon`.
+            ///
+            /// ```
+            /// use ndarray::{Array, Zip};
+            ///
+            /// let a = Array::from_shape_fn((100, 100), |(i, j)| (i + j) as i64);
+            /// let b = Array::from_shape_fn((100, 100), |(i, j)| (i * j) as i64 % 7);
+            ///
+            /// let dot = Zip::from(&a).and(&b).par_fold(
+            ///     || 0,
+            ///     |acc, &x, &y| acc + x * y,
+            ///     |x, y| x + y,
+            /// );
+            /// assert_eq!(dot, (&a * &b).sum());
+            /// ```
+            pub fn par_fold<T, I, F, R>(self, identity: I, fold: F, reduce: R) -> T
+                where I: Fn() -> T + Sync + Send,
+                      F: Fn(T, $($p::Item),*) -> T + Sync + Send,
+                      R: Fn(T, T) -> T + Sync + Send,
+                      T: Send,
+            {
+                ParallelSplits {
+                    iter: self,
+                    max_splits: COLLECT_MAX_SPLITS,
+                }
+                .map(|zip| zip.fold(identity(), |acc, $($p),*| fold(acc, $($p),*)))
+                .reduce(&identity, &reduce)
+            }
+
             expand_if!(@bool [$notlast]
 
             /// Map and collect the results into a new array, which has the same size as the
@@ -6,6 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use num_traits::{FromPrimitive, Zero};
+use std::ops::{Add, Div};
+
+use alloc::vec::Vec;
+
 use crate::error::{from_kind, ErrorKind, ShapeError};
 use crate::imp_prelude::*;
 
@@ -108,6 +113,172 @@ where
     }
 }
 
+/// Stack arrays along a new axis and reduce that axis, without creating the
+/// stacked array.
+///
+/// For each position, `reduce` is called with the elements of all the arrays at that
+/// position (in the order of `arrays`), which is the lane of the stacked array along the
+/// new `axis`. The result has the same shape as the input arrays.
+///
+/// Since the new axis is reduced away, `axis` does not affect the result; it is only
+/// checked to be in bounds, like for [`stack`].
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty or if `axis` is out of bounds.
+///
+/// ```
+/// use ndarray::{arr2, stack_reduce, Axis};
+///
+/// let a = arr2(&[[1., 2.], [3., 4.]]);
+/// let b = arr2(&[[3., 0.], [3., 2.]]);
+/// let mean = stack_reduce(Axis(0), &[a.view(), b.view()], |xs| {
+///     xs.iter().copied().sum::<f64>() / xs.len() as f64
+/// });
+/// assert_eq!(mean, Ok(arr2(&[[2., 1.], [3., 3.]])));
+/// ```
+pub fn stack_reduce<A, B, D, F>(
+    axis: Axis,
+    arrays: &[ArrayView<A, D>],
+    mut reduce: F,
+) -> Result<Array<B, D>, ShapeError>
+where
+    D: Dimension,
+    F: FnMut(&[&A]) -> B,
+{
+    if arrays.is_empty() {
+        return Err(from_kind(ErrorKind::Unsupported));
+    }
+    let common_dim = arrays[0].raw_dim();
+    if axis.index() > common_dim.ndim() {
+        return Err(from_kind(ErrorKind::OutOfBounds));
+    }
+    if arrays.iter().any(|a| a.raw_dim() != common_dim) {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+
+    // Walk all arrays in logical order in lockstep, collecting one lane at a time
+    let mut iters: Vec<_> = arrays.iter().map(|a| a.iter()).collect();
+    let mut lane = Vec::with_capacity(arrays.len());
+    let mut res = Vec::with_capacity(common_dim.size());
+    for _ in 0..common_dim.size() {
+        lane.clear();
+        lane.extend(iters.iter_mut().map(|iter| iter.next().unwrap()));
+        res.push(reduce(&lane));
+    }
+    unsafe {
+        Ok(Array::from_shape_vec_unchecked(common_dim, res))
+    }
+}
+
+/// Return the element-wise maximum of equally shaped arrays.
+///
+/// If any of the elements at a position is not comparable to itself (like
+/// NaN), the result at that position is such an element.
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty.
+///
+/// ```
+/// use ndarray::{arr2, elementwise_max};
+///
+/// let a = arr2(&[[1, 5], [3, 0]]);
+/// let b = arr2(&[[4, 2], [3, -1]]);
+/// assert_eq!(
+///     elementwise_max(&[a.view(), b.view()]),
+///     Ok(arr2(&[[4, 5], [3, 0]]))
+/// );
+/// ```
+pub fn elementwise_max<A, D>(arrays: &[ArrayView<A, D>]) -> Result<Array<A, D>, ShapeError>
+where
+    A: Clone + PartialOrd,
+    D: Dimension,
+{
+    elementwise_fold(arrays, |acc, x| {
+        if *x > *acc || x.partial_cmp(x).is_none() {
+            *acc = x.clone();
+        }
+    })
+}
+
+/// Return the element-wise minimum of equally shaped arrays.
+///
+/// If any of the elements at a position is not comparable to itself (like
+/// NaN), the result at that position is such an element.
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty.
+///
+/// ```
+/// use ndarray::{arr2, elementwise_min};
+///
+/// let a = arr2(&[[1, 5], [3, 0]]);
+/// let b = arr2(&[[4, 2], [3, -1]]);
+/// assert_eq!(
+///     elementwise_min(&[a.view(), b.view()]),
+///     Ok(arr2(&[[1, 2], [3, -1]]))
+/// );
+/// ```
+pub fn elementwise_min<A, D>(arrays: &[ArrayView<A, D>]) -> Result<Array<A, D>, ShapeError>
+where
+    A: Clone + PartialOrd,
+    D: Dimension,
+{
+    elementwise_fold(arrays, |acc, x| {
+        if *x < *acc || x.partial_cmp(x).is_none() {
+            *acc = x.clone();
+        }
+    })
+}
+
+/// Return the element-wise arithmetic mean of equally shaped arrays.
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty.
+///
+/// ```
+/// use ndarray::{arr2, elementwise_mean};
+///
+/// let a = arr2(&[[1., 5.], [3., 0.]]);
+/// let b = arr2(&[[4., 2.], [3., -1.]]);
+/// assert_eq!(
+///     elementwise_mean(&[a.view(), b.view()]),
+///     Ok(arr2(&[[2.5, 3.5], [3., -0.5]]))
+/// );
+/// ```
+pub fn elementwise_mean<A, D>(arrays: &[ArrayView<A, D>]) -> Result<Array<A, D>, ShapeError>
+where
+    A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A>,
+    D: Dimension,
+{
+    let mut sum = elementwise_fold(arrays, |acc, x| *acc = acc.clone() + x.clone())?;
+    let n = A::from_usize(arrays.len())
+        .expect("Converting number of arrays to `A` must not fail.");
+    sum.map_inplace(|x| *x = x.clone() / n.clone());
+    Ok(sum)
+}
+
+/// Combine equally shaped arrays element by element, starting from a copy of the
+/// first array.
+fn elementwise_fold<A, D, F>(arrays: &[ArrayView<A, D>], mut f: F) -> Result<Array<A, D>, ShapeError>
+where
+    A: Clone,
+    D: Dimension,
+    F: FnMut(&mut A, &A),
+{
+    let (first, rest) = match arrays.split_first() {
+        Some(split) => split,
+        None => return Err(from_kind(ErrorKind::Unsupported)),
+    };
+    if rest.iter().any(|a| a.shape() != first.shape()) {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    let mut res = first.to_owned();
+    for array in rest {
+        res.zip_mut_with(array, &mut f);
+    }
+    Ok(res)
+}
+
 #[deprecated(note="Use under the name stack instead.", since="0.15.0")]
 /// Stack arrays along the new axis.
 ///
@@ -212,6 +383,44 @@ macro_rules! stack {
     }
 }
 
+/// Assemble a block diagonal matrix from the matrices `blocks`.
+///
+/// The blocks are placed along the diagonal in order, each starting at the row and
+/// column after the end of the previous block, and all other elements are zero. The
+/// result has the sum of the numbers of rows of the blocks as its number of rows, and
+/// likewise for columns; the blocks don't need to be square. The result is empty if
+/// `blocks` is empty.
+///
+/// ```
+/// use ndarray::{arr2, block_diag};
+///
+/// let a = arr2(&[[1, 2],
+///                [3, 4]]);
+/// let b = arr2(&[[5, 6, 7]]);
+/// assert_eq!(
+///     block_diag(&[a.view(), b.view()]),
+///     arr2(&[[1, 2, 0, 0, 0],
+///            [3, 4, 0, 0, 0],
+///            [0, 0, 5, 6, 7]])
+/// );
+/// ```
+pub fn block_diag<A>(blocks: &[ArrayView2<A>]) -> Array2<A>
+where
+    A: Clone + Zero,
+{
+    let nrows = blocks.iter().map(|b| b.nrows()).sum();
+    let ncols = blocks.iter().map(|b| b.ncols()).sum();
+    let mut res = Array2::zeros((nrows, ncols));
+    let (mut row, mut col) = (0, 0);
+    for block in blocks {
+        let (m, n) = block.dim();
+        res.slice_mut(s![row..row + m, col..col + n]).assign(block);
+        row += m;
+        col += n;
+    }
+    res
+}
+
 /// Concatenate arrays along the given axis.
 ///
 /// Uses the [`concatenate`][1] function, calling `ArrayView::from(&a)` on each
@@ -10,7 +10,6 @@
 mod zipmacro;
 mod ndproducer;
 
-#[cfg(feature = "rayon")]
 use std::mem::MaybeUninit;
 
 use crate::imp_prelude::*;
@@ -419,7 +418,6 @@ where
         FoldWhile::Continue(acc)
     }
 
-    #[cfg(feature = "rayon")]
     pub(crate) fn uninitalized_for_current_layout<T>(&self) -> Array<MaybeUninit<T>, D>
     {
         let is_f = self.prefer_f();
@@ -617,6 +615,70 @@ macro_rules! map_impl {
                 })
             }
 
+            /// Apply a fallible fold function to all elements of the input arrays,
+            /// visiting elements in lock step, and stop at the first error.
+            ///
+            /// Return the final accumulator, or the first error returned by `function`,
+            /// like `Iterator::try_fold`.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![[1u8, 2], [3, 4]];
+            /// let b = array![[5u8, 6], [7, 8]];
+            ///
+            /// // Sum the products, failing if there is an overflow
+            /// let sum = Zip::from(&a).and(&b).try_fold(0u8, |acc, &x, &y| {
+            ///     x.checked_mul(y).and_then(|xy| acc.checked_add(xy)).ok_or("overflow")
+            /// });
+            /// assert_eq!(sum, Ok(70));
+            ///
+            /// let sum = Zip::from(&a).and(&b).try_fold(0u8, |acc, &x, &y| {
+            ///     x.checked_pow(y as u32).and_then(|p| acc.checked_add(p)).ok_or("overflow")
+            /// });
+            /// assert_eq!(sum, Err("overflow"));
+            /// ```
+            pub fn try_fold<F, Acc, E>(mut self, acc: Acc, mut function: F) -> Result<Acc, E>
+                where F: FnMut(Acc, $($p::Item),*) -> Result<Acc, E>
+            {
+                self.for_each_core(Ok(acc), move |acc: Result<Acc, E>, args| {
+                    let ($($p,)*) = args;
+                    let acc = acc.and_then(|acc| function(acc, $($p),*));
+                    if acc.is_ok() {
+                        FoldWhile::Continue(acc)
+                    } else {
+                        FoldWhile::Done(acc)
+                    }
+                }).into_inner()
+            }
+
+            /// Apply a fallible function to all elements of the input arrays,
+            /// visiting elements in lock step, and stop at the first error.
+            ///
+            /// Return the first error returned by `function`, if any, like
+            /// `Iterator::try_for_each`.
+            ///
+            /// ```
+            /// use ndarray::{array, Array, Zip};
+            ///
+            /// let a = array![250u8, 3, 4];
+            /// let b = array![2u8, 9, 1];
+            /// let mut sum = Array::zeros(3);
+            ///
+            /// let zip = Zip::from(&mut sum).and(&a).and(&b);
+            /// let result: Result<(), &str> = zip.try_for_each(|s, &x, &y| {
+            ///     *s = x.checked_add(y).ok_or("overflow")?;
+            ///     Ok(())
+            /// });
+            /// assert_eq!(result, Ok(()));
+            /// assert_eq!(sum, array![252, 12, 5]);
+            /// ```
+            pub fn try_for_each<F, E>(self, mut function: F) -> Result<(), E>
+                where F: FnMut($($p::Item),*) -> Result<(), E>
+            {
+                self.try_fold((), move |(), $($p),*| function($($p),*))
+            }
+
             /// Tests if every element of the iterator matches a predicate.
             ///
             /// Returns `true` if `predicate` evaluates to `true` for all elements.
@@ -709,6 +709,265 @@ fn test_select() {
     assert_abs_diff_eq!(c, c_target);
 }
 
+#[test]
+fn test_select_clone_repeated() {
+    let x = arr2(&[["a", "b"], ["c", "d"], ["e", "f"]]).mapv(String::from);
+    let r = x.select(Axis(0), &[2, 0, 2]);
+    assert_eq!(r, arr2(&[["e", "f"], ["a", "b"], ["e", "f"]]).mapv(String::from));
+    let c = x.select(Axis(1), &[1, 1, 0]);
+    assert_eq!(
+        c,
+        arr2(&[["b", "b", "a"], ["d", "d", "c"], ["f", "f", "e"]]).mapv(String::from)
+    );
+    assert!(c.is_standard_layout());
+
+    let e = x.select(Axis(1), &[]);
+    assert_eq!(e.shape(), &[3, 0]);
+
+    let v = aview1(&[1, 2, 3]).select(Axis(0), &[2, 1, 0]);
+    assert_eq!(v, arr1(&[3, 2, 1]));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_select_oob() {
+    let x = arr2(&[[1, 2], [3, 4]]);
+    x.select(Axis(0), &[0, 2]);
+}
+
+#[test]
+fn test_take_along_axis() {
+    let a = arr2(&[[10, 11, 12], [20, 21, 22]]);
+    let idx = arr2(&[[2, 0, 0, 1], [1, 1, 2, 0]]);
+    assert_eq!(
+        a.take_along_axis(&idx, Axis(1)),
+        arr2(&[[12, 10, 10, 11], [21, 21, 22, 20]])
+    );
+    let idx = arr2(&[[1, 0, 1]]);
+    assert_eq!(a.take_along_axis(&idx, Axis(0)), arr2(&[[20, 11, 22]]));
+
+    // indices broadcast along the other axes
+    let idx = arr2(&[[2], [0]]).reversed_axes();
+    assert_eq!(a.take_along_axis(&idx, Axis(1)), arr2(&[[12, 10], [22, 20]]));
+
+    // 3D with non-Copy elements and an empty result
+    let b = Array::from_shape_fn((2, 3, 2), |(i, j, k)| format!("{}{}{}", i, j, k));
+    let idx = Array::from_shape_fn((2, 1, 2), |(i, _, k)| (i + k) % 3);
+    let r = b.take_along_axis(&idx, Axis(1));
+    assert_eq!(r.shape(), &[2, 1, 2]);
+    assert_eq!(r[[0, 0, 0]], "000");
+    assert_eq!(r[[1, 0, 1]], "121");
+    let idx = Array3::<usize>::zeros((2, 0, 2));
+    assert_eq!(b.take_along_axis(&idx, Axis(1)).shape(), &[2, 0, 2]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_take_along_axis_oob() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    a.take_along_axis(&arr2(&[[0], [2]]), Axis(1));
+}
+
+#[test]
+#[should_panic(expected = "broadcast")]
+fn test_take_along_axis_wrong_shape() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    a.take_along_axis(&arr2(&[[0], [1], [0]]), Axis(1));
+}
+
+#[test]
+fn test_roll_axis() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| format!("{}{}{}", i, j, k));
+    for axis in 0..3 {
+        let len = a.len_of(Axis(axis)) as isize;
+        for &shift in &[0, 1, 2, -1, -7, len, 13, isize::MIN, isize::MAX] {
+            let r = a.roll_axis(Axis(axis), shift);
+            for (index, elt) in a.indexed_iter() {
+                let mut index = [index.0, index.1, index.2];
+                index[axis] = (index[axis] as i128 + shift as i128).rem_euclid(len as i128) as usize;
+                assert_eq!(&r[index], elt);
+            }
+            // in place, with contiguous and non-contiguous lanes
+            let mut c = a.clone();
+            c.roll_axis_inplace(Axis(axis), shift);
+            assert_eq!(c, r);
+            let mut f = a.t().to_owned();
+            f.roll_axis_inplace(Axis(2 - axis), shift);
+            assert_eq!(f.t(), r);
+        }
+    }
+
+    let mut s = arr2(&[[1, 2, 3, 4], [5, 6, 7, 8]]);
+    assert_eq!(s.slice(s![.., ..;-2]).roll_axis(Axis(1), 1), arr2(&[[2, 4], [6, 8]]));
+    s.slice_mut(s![.., ..;2]).roll_axis_inplace(Axis(0), 1);
+    assert_eq!(s, arr2(&[[5, 2, 7, 4], [1, 6, 3, 8]]));
+
+    let mut empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.roll_axis(Axis(0), 2).shape(), &[0, 3]);
+    empty.roll_axis_inplace(Axis(0), -2);
+}
+
+#[test]
+fn test_tile() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let t = a.tile((2, 3));
+    assert_eq!(t.shape(), &[4, 9]);
+    for ((i, j), elt) in t.indexed_iter() {
+        assert_eq!(*elt, a[[i % 2, j % 3]]);
+    }
+    assert_eq!(a.tile((1, 1)), a);
+    assert_eq!(a.tile((0, 2)).shape(), &[0, 6]);
+    assert_eq!(Array2::<i32>::zeros((0, 2)).tile((3, 2)).shape(), &[0, 4]);
+
+    // non-Copy elements and a transposed view
+    let s = arr2(&[["a", "b"], ["c", "d"]]).mapv(String::from);
+    let t = s.t().tile((1, 2));
+    assert_eq!(t, arr2(&[["a", "c", "a", "c"], ["b", "d", "b", "d"]]).mapv(String::from));
+
+    let d = Array::from_shape_fn(IxDyn(&[2, 1, 2]), |ix| ix[0] * 10 + ix[2]);
+    let t = d.tile(IxDyn(&[1, 3, 2]));
+    assert_eq!(t.shape(), &[2, 3, 4]);
+    assert_eq!(t.slice(s![1, 2, ..]).to_vec(), vec![10, 11, 10, 11]);
+}
+
+#[test]
+fn test_repeat_axis() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.repeat_axis(Axis(0), 2), arr2(&[[1, 2, 3], [1, 2, 3], [4, 5, 6], [4, 5, 6]]));
+    assert_eq!(
+        a.repeat_axis(Axis(1), 3),
+        arr2(&[[1, 1, 1, 2, 2, 2, 3, 3, 3], [4, 4, 4, 5, 5, 5, 6, 6, 6]])
+    );
+    assert_eq!(a.repeat_axis(Axis(1), 1), a);
+    assert_eq!(a.repeat_axis(Axis(1), 0).shape(), &[2, 0]);
+
+    // repeat differs from tile
+    let v = arr1(&[1, 2]);
+    assert_eq!(v.repeat_axis(Axis(0), 2), arr1(&[1, 1, 2, 2]));
+    assert_eq!(v.tile(2), arr1(&[1, 2, 1, 2]));
+}
+
+#[test]
+fn test_flip() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.flip(Axis(0)), arr2(&[[4, 5, 6], [1, 2, 3]]));
+    assert_eq!(a.flip(Axis(1)), arr2(&[[3, 2, 1], [6, 5, 4]]));
+    assert_eq!(a.flipud(), a.flip(Axis(0)));
+    assert_eq!(a.fliplr(), a.flip(Axis(1)));
+    assert_eq!(a.flip2(), arr2(&[[6, 5, 4], [3, 2, 1]]));
+    assert_eq!(a.flip_axes(&[Axis(1), Axis(0)]), a.flip2());
+    assert_eq!(a.flip_axes(&[Axis(1), Axis(1)]), a);
+    assert_eq!(a.flip_axes(&[]), a);
+
+    // the views share data with the array
+    let ptr_range = a.as_ptr()..a.as_ptr().wrapping_add(a.len());
+    assert!(ptr_range.contains(&a.flip2().as_ptr()));
+    assert_eq!(a.flip(Axis(0)).strides(), &[-3, 1]);
+    assert_eq!(&a.flip2()[[0, 0]] as *const i32, &a[[1, 2]] as *const i32);
+
+    let f = a.clone().flipped(Axis(1));
+    assert_eq!(f, a.fliplr());
+    assert_eq!(f.strides(), &[3, -1]);
+    assert_eq!(a.view().flipped(Axis(0)).flipped(Axis(0)), a);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.flip2().shape(), &[0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_flip_axis_oob() {
+    arr2(&[[1, 2]]).flip(Axis(2));
+}
+
+#[test]
+fn test_triu_tril() {
+    let a = Array::from_shape_vec((3, 4), (1..13).collect()).unwrap();
+    assert_eq!(a.triu(0), arr2(&[[1, 2, 3, 4], [0, 6, 7, 8], [0, 0, 11, 12]]));
+    assert_eq!(a.triu(2), arr2(&[[0, 0, 3, 4], [0, 0, 0, 8], [0, 0, 0, 0]]));
+    assert_eq!(a.triu(-1), arr2(&[[1, 2, 3, 4], [5, 6, 7, 8], [0, 10, 11, 12]]));
+    assert_eq!(a.tril(0), arr2(&[[1, 0, 0, 0], [5, 6, 0, 0], [9, 10, 11, 0]]));
+    assert_eq!(a.tril(1), arr2(&[[1, 2, 0, 0], [5, 6, 7, 0], [9, 10, 11, 12]]));
+    assert_eq!(a.tril(-2), arr2(&[[0, 0, 0, 0], [0, 0, 0, 0], [9, 0, 0, 0]]));
+
+    // offsets beyond the matrix keep everything or nothing
+    assert_eq!(a.triu(-5), a);
+    assert_eq!(a.triu(4), Array2::zeros((3, 4)));
+    assert_eq!(a.tril(3), a);
+    assert_eq!(a.tril(-3), Array2::zeros((3, 4)));
+    assert_eq!(a.triu(isize::MIN), a);
+    assert_eq!(a.tril(isize::MAX), a);
+
+    // the two triangles partition the matrix
+    for k in -4..5 {
+        assert_eq!(a.triu(k) + a.tril(k - 1), a);
+    }
+    assert_eq!(a.t().triu(0), a.tril(0).reversed_axes());
+}
+
+#[test]
+fn test_cartesian() {
+    use ndarray::{cartesian, cartesian_columns};
+
+    let a = arr1(&[1, 2, 3]);
+    let b = arr1(&["u", "v"]);
+    let grid = cartesian(&a.view(), &b.view());
+    assert_eq!(grid.shape(), &[3, 2]);
+    let pairs: Vec<_> = grid.iter().cloned().collect();
+    assert_eq!(
+        pairs,
+        vec![(1, "u"), (1, "v"), (2, "u"), (2, "v"), (3, "u"), (3, "v")]
+    );
+    for &x in &a {
+        for &y in &b {
+            assert_eq!(pairs.iter().filter(|&&p| p == (x, y)).count(), 1);
+        }
+    }
+
+    let (xs, ys) = cartesian_columns(&a.view(), &b.view());
+    assert_eq!(xs.len(), 6);
+    assert_eq!(ys.len(), 6);
+    assert_eq!(xs.iter().cloned().zip(ys.iter().cloned()).collect::<Vec<_>>(), pairs);
+
+    // reversed and empty inputs
+    let r = a.slice(s![..;-1]);
+    assert_eq!(cartesian(&r, &b.view())[[0, 1]], (3, "v"));
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(cartesian(&a.view(), &empty.view()).shape(), &[3, 0]);
+    let (xs, ys) = cartesian_columns(&empty.view(), &b.view());
+    assert!(xs.is_empty() && ys.is_empty());
+}
+
+#[test]
+fn test_rot90() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.rot90(1), arr2(&[[3, 6], [2, 5], [1, 4]]));
+    assert_eq!(a.rot90(2), arr2(&[[6, 5, 4], [3, 2, 1]]));
+    assert_eq!(a.rot90(3), arr2(&[[4, 1], [5, 2], [6, 3]]));
+    assert_eq!(a.rot90(-1), a.rot90(3));
+    assert_eq!(a.rot90(4), a);
+    assert_eq!(a.rot90(0), a);
+    assert_eq!(a.rot90(1).rot90(1), a.rot90(2));
+    assert_eq!(a.rot90(1).rot90(-1), a);
+    assert_eq!(a.t().rot90(2), a.rot90(2).t());
+
+    // only the first two axes are rotated
+    let b = Array::from_shape_vec((2, 3, 2), (0..12).collect()).unwrap();
+    let r = b.rot90(1);
+    assert_eq!(r.shape(), &[3, 2, 2]);
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(r.slice(s![2 - j, i, ..]), b.slice(s![i, j, ..]));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rot90_1d() {
+    arr1(&[1, 2, 3]).rot90(1);
+}
+
 #[test]
 fn diag() {
     let d = arr2(&[[1., 2., 3.0f32]]).into_diag();
@@ -722,6 +981,29 @@ fn diag() {
     assert_eq!(d.dim(), 1);
 }
 
+#[test]
+fn diag_mut_and_fill_diagonal() {
+    let mut a = Array::from_shape_vec((3, 4), (0..12).collect()).unwrap();
+    {
+        let mut d = a.diag_mut();
+        assert_eq!(d.len(), 3);
+        assert_eq!(d.strides(), &[5]);
+        d += 100;
+    }
+    assert_eq!(a, arr2(&[[100, 1, 2, 3], [4, 105, 6, 7], [8, 9, 110, 11]]));
+
+    a.fill_diagonal(-1);
+    assert_eq!(a.diag(), arr1(&[-1, -1, -1]));
+    assert_eq!(a.sum(), 66 - 15 - 3);
+
+    // tall arrays, and diagonals of non-contiguous views
+    let mut b = Array2::<i32>::zeros((4, 2));
+    b.view_mut().reversed_axes().fill_diagonal(7);
+    assert_eq!(b, arr2(&[[7, 0], [0, 7], [0, 0], [0, 0]]));
+    b.slice_mut(s![1.., ..;-1]).fill_diagonal(3);
+    assert_eq!(b, arr2(&[[7, 0], [0, 3], [3, 0], [0, 0]]));
+}
+
 /// Check that the merged shape is correct.
 ///
 /// Note that this does not check the strides in the "merged" case!
@@ -976,6 +1258,113 @@ fn map1() {
     assert_eq!(a[(0, 0)], *c[(0, 0)]);
 }
 
+#[test]
+fn try_map1() {
+    let a = arr2(&[["1.5", "2"], ["-3", "4e2"]]);
+    let b = a.try_mapv(|s| s.parse::<f64>()).unwrap();
+    assert_eq!(b, arr2(&[[1.5, 2.], [-3., 400.]]));
+    // transposed input gives a standard layout result
+    let bt = a.t().try_map(|s| s.parse::<f64>()).unwrap();
+    assert_eq!(bt, b.t());
+    assert!(bt.is_standard_layout());
+
+    let a = arr2(&[["1", "x"], ["3", "y"]]);
+    let mut visited = Vec::new();
+    let res = a.try_mapv(|s| {
+        visited.push(s);
+        s.parse::<f64>()
+    });
+    assert!(res.is_err());
+    assert_eq!(visited, vec!["1", "x"]);
+}
+
+#[test]
+fn zip_mut_with_accumulate() {
+    let mut a = Array::<i32, _>::zeros((3, 4));
+    let b = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    for _ in 0..3 {
+        a.zip_mut_with(&b, |x, &y| *x += y);
+    }
+    assert_eq!(a, &b * 3);
+
+    // non-contiguous operands
+    let mut a = Array::<i32, _>::zeros((4, 3));
+    a.zip_mut_with(&b.t(), |x, &y| *x += y);
+    assert_eq!(a, b.t());
+}
+
+#[test]
+#[should_panic]
+fn zip_mut_with_shape_mismatch() {
+    let mut a = Array::<i32, _>::zeros((3, 4));
+    let b = Array::<i32, _>::zeros((4, 3));
+    a.zip_mut_with(&b, |x, &y| *x += y);
+}
+
+#[test]
+fn argwhere() {
+    let a = arr2(&[[1., 5., 2.], [7., 0., 9.], [3., 4., 6.]]);
+    let idx = a.argwhere(|&x| x > 4.);
+    assert_eq!(idx, arr2(&[[0, 1], [1, 0], [1, 2], [2, 2]]));
+    for row in idx.rows() {
+        assert!(a[[row[0], row[1]]] > 4.);
+    }
+    assert_eq!(a.t().argwhere(|&x| x > 4.), arr2(&[[0, 1], [1, 0], [2, 1], [2, 2]]));
+    assert_eq!(a.argwhere(|&x| x > 10.).shape(), &[0, 2]);
+
+    let b = Array::from_shape_fn((2, 2, 2), |(i, j, k)| i + j + k);
+    assert_eq!(b.into_dyn().argwhere(|&x| x == 3), arr2(&[[1, 1, 1]]));
+    assert_eq!(arr0(1).argwhere(|&x| x == 1).shape(), &[1, 0]);
+}
+
+#[test]
+fn apply_at_indices() {
+    let mut a = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as i32);
+    let b = a.clone();
+    let indices = [(0, 0), (3, 4), (1, 2), (2, 0)];
+    a.apply_at_indices(&indices, |x| *x += 100);
+    for ((i, j), &x) in a.indexed_iter() {
+        if indices.contains(&(i, j)) {
+            assert_eq!(x, b[(i, j)] + 100);
+        } else {
+            assert_eq!(x, b[(i, j)]);
+        }
+    }
+
+    // through a transposed view
+    let mut v = a.view_mut().reversed_axes();
+    v.apply_at_indices(&[(4, 3)], |x| *x = -1);
+    assert_eq!(a[(3, 4)], -1);
+
+    let mut d = Array::<i32, _>::zeros(IxDyn(&[2, 2, 2]));
+    d.apply_at_indices(&[IxDyn(&[1, 0, 1])], |x| *x = 7);
+    assert_eq!(d.sum(), 7);
+    assert_eq!(d[[1, 0, 1]], 7);
+}
+
+#[test]
+#[should_panic]
+fn apply_at_indices_out_of_bounds() {
+    let mut a = Array::<i32, _>::zeros((2, 2));
+    a.apply_at_indices(&[(0, 0), (2, 0)], |x| *x += 1);
+}
+
+#[test]
+fn mask_where() {
+    let mut a = arr2(&[[1, -2, 3], [-4, -5, 6]]);
+    let negatives = a.iter().filter(|&&x| x < 0).count();
+    assert_eq!(a.mask_where(|&x| x < 0, 0), negatives);
+    assert_eq!(a, arr2(&[[1, 0, 3], [0, 0, 6]]));
+    assert_eq!(a.mask_where(|&x| x < 0, 0), 0);
+
+    // strided view
+    let mut b = Array::from_shape_fn((4, 4), |(i, j)| i as i32 - j as i32);
+    let n = b.slice_mut(s![..;2, ..;2]).mask_where(|&x| x < 0, 100);
+    assert_eq!(n, 1);
+    assert_eq!(b[(0, 2)], 100);
+    assert_eq!(b[(0, 1)], -1);
+}
+
 #[test]
 fn as_slice_memory_order() {
     // test that mutation breaks sharing
@@ -1235,6 +1624,34 @@ fn from_vec_dim_stride_2d_rejects() {
     assert_matches!(Array::from_shape_vec(d.strides(s), two.to_vec()), Err(_));
 }
 
+#[test]
+fn from_vec_dim_stride_reconstruct() {
+    // reconstruct arrays from shape, strides and data in memory order
+    let a = Array::from_shape_fn((2, 3, 4).f(), |(i, j, k)| i * 100 + j * 10 + k);
+    let shape = a.raw_dim();
+    let strides = Dim([1, 2, 6]);
+    assert_eq!(a.strides(), &[1, 2, 6]);
+    let b = Array::from_shape_vec(shape.strides(strides), a.clone().into_raw_vec()).unwrap();
+    assert_eq!(b, a);
+    assert_eq!(b.strides(), a.strides());
+
+    // every other row of a larger buffer, with a gap at the end
+    let data: Vec<i32> = (0..14).collect();
+    let c = Array::from_shape_vec((3, 2).strides((4, 1)), data).unwrap();
+    assert_eq!(c, arr2(&[[0, 1], [4, 5], [8, 9]]));
+}
+
+#[test]
+fn from_vec_dim_stride_out_of_bounds() {
+    // the last element would be at offset 2 * 4 + 1 = 9
+    let data: Vec<i32> = (0..9).collect();
+    let res = Array::from_shape_vec((3, 2).strides((4, 1)), data);
+    assert_eq!(res.unwrap_err().kind(), ndarray::ErrorKind::OutOfBounds);
+
+    let res = Array::from_shape_vec((2, 3, 4).strides((1, 2, 6)), vec![0; 23]);
+    assert_eq!(res.unwrap_err().kind(), ndarray::ErrorKind::OutOfBounds);
+}
+
 #[test]
 fn views() {
     let a = ArcArray::from(vec![1, 2, 3, 4]).reshape((2, 2));
@@ -2175,6 +2592,101 @@ fn test_array2_from_diag() {
     assert_eq!(x.shape(), [0, 0]);
 }
 
+#[test]
+fn test_array2_toeplitz() {
+    let col = arr1(&[1, 2, 3, 4]);
+    let row = arr1(&[1, 5, 6]);
+    let x = Array2::toeplitz(&col, &row);
+    assert_eq!(x, arr2(&[[1, 5, 6], [2, 1, 5], [3, 2, 1], [4, 3, 2]]));
+    // constant along each diagonal
+    for ((i, j), &elt) in x.indexed_iter() {
+        if i > 0 && j > 0 {
+            assert_eq!(elt, x[[i - 1, j - 1]]);
+        }
+    }
+    // the main diagonal comes from the first column
+    let x = Array2::toeplitz(&arr1(&[1, 2]), &arr1(&[9, 3]));
+    assert_eq!(x, arr2(&[[1, 3], [2, 1]]));
+
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(Array2::toeplitz(&empty, &row).shape(), [0, 3]);
+}
+
+#[test]
+fn test_array2_circulant() {
+    let c = arr1(&[1., 2., 3., 4.]);
+    let x = Array2::circulant(&c);
+    assert_eq!(
+        x,
+        arr2(&[[1., 4., 3., 2.], [2., 1., 4., 3.], [3., 2., 1., 4.], [4., 3., 2., 1.]])
+    );
+    assert_eq!(x.column(0), c);
+    for ((i, j), &elt) in x.indexed_iter() {
+        assert_eq!(elt, x[[(i + 1) % 4, (j + 1) % 4]]);
+    }
+    assert_eq!(Array2::toeplitz(&c, &arr1(&[1., 4., 3., 2.])), x);
+
+    assert_eq!(Array2::circulant(&Array1::<f64>::zeros(0)).shape(), [0, 0]);
+}
+
+#[test]
+fn test_array2_hankel() {
+    let col = arr1(&[1, 2, 3, 4]);
+    let x = Array2::hankel(&col, None);
+    assert_eq!(x, arr2(&[[1, 2, 3, 4], [2, 3, 4, 0], [3, 4, 0, 0], [4, 0, 0, 0]]));
+
+    let row = arr1(&[9, 5, 6]);
+    let x = Array2::hankel(&col, Some(&row.view()));
+    assert_eq!(x, arr2(&[[1, 2, 3], [2, 3, 4], [3, 4, 5], [4, 5, 6]]));
+    // constant along each anti-diagonal
+    for ((i, j), &elt) in x.indexed_iter() {
+        if i > 0 && j + 1 < x.ncols() {
+            assert_eq!(elt, x[[i - 1, j + 1]]);
+        }
+    }
+    // a Hankel matrix is a Toeplitz matrix with the rows reversed
+    let mut rev_col = x.column(0).to_vec();
+    rev_col.reverse();
+    let toeplitz = Array2::toeplitz(&arr1(&rev_col), &x.row(3));
+    assert_eq!(x.slice(s![..;-1, ..]), toeplitz);
+
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(Array2::hankel(&empty, None).shape(), [0, 0]);
+    assert_eq!(Array2::hankel(&col, Some(&empty.view())).shape(), [4, 0]);
+}
+
+#[test]
+fn test_array2_companion() {
+    // x² - 3x + 2 = (x - 1)(x - 2)
+    let x = Array2::companion(&arr1(&[1., -3., 2.]));
+    assert_eq!(x, arr2(&[[3., -2.], [1., 0.]]));
+    // trace and determinant are the sum and product of the roots
+    assert_eq!(x.diag().sum(), 3.);
+    assert_eq!(x[[0, 0]] * x[[1, 1]] - x[[0, 1]] * x[[1, 0]], 2.);
+
+    // normalized by the leading coefficient
+    assert_eq!(Array2::companion(&arr1(&[2., -6., 4.])), x);
+
+    let x = Array2::companion(&arr1(&[2., 4., -6., 8., 10.]));
+    assert_eq!(
+        x,
+        arr2(&[[-2., 3., -4., -5.], [1., 0., 0., 0.], [0., 1., 0., 0.], [0., 0., 1., 0.]])
+    );
+    assert_eq!(Array2::companion(&arr1(&[4., 2.])), arr2(&[[-0.5]]));
+}
+
+#[test]
+#[should_panic]
+fn test_array2_companion_degree_zero() {
+    Array2::companion(&arr1(&[1.]));
+}
+
+#[test]
+#[should_panic]
+fn test_array2_companion_zero_leading() {
+    Array2::companion(&arr1(&[0., 1., 2.]));
+}
+
 #[test]
 fn array_macros() {
     // array
@@ -2467,9 +2979,374 @@ fn test_remove_index_oob2() {
     a.remove_index(Axis(1), 0); // oob
 }
 
+#[test]
+fn test_remove_index_compact_rows() {
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (10 * i + j) as i32);
+    a.remove_index(Axis(0), 1);
+    assert_eq!(a, array![[0, 1, 2], [20, 21, 22], [30, 31, 32]]);
+    a.remove_index(Axis(0), 2);
+    a.remove_index(Axis(0), 0);
+    assert_eq!(a, array![[20, 21, 22]]);
+    assert!(a.is_standard_layout());
+    a.remove_index(Axis(0), 0);
+    assert_eq!(a.shape(), &[0, 3]);
+    assert_eq!(a.into_raw_vec().len(), 0);
+}
+
+#[test]
+fn test_remove_index_compact_columns() {
+    let mut a = Array::from_shape_vec((2, 3).f(), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    a.remove_index(Axis(1), 0);
+    assert_eq!(a, array![[3, 5], [4, 6]]);
+    assert!(a.t().is_standard_layout());
+    assert_eq!(a.into_raw_vec(), vec![3, 4, 5, 6]);
+}
+
+#[test]
+fn test_remove_index_other_layouts() {
+    // columns of a C-order array, and an array with negative strides
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (10 * i + j) as i32);
+    a.remove_index(Axis(1), 1);
+    assert_eq!(a, array![[0, 2, 3], [10, 12, 13], [20, 22, 23]]);
+    a.invert_axis(Axis(0));
+    a.remove_index(Axis(0), 2);
+    assert_eq!(a, array![[20, 22, 23], [10, 12, 13]]);
+    a.remove_index(Axis(1), 2);
+    assert_eq!(a, array![[20, 22], [10, 12]]);
+
+    let mut b = Array3::from_shape_fn((2, 3, 2), |(i, j, k)| (100 * i + 10 * j + k) as i32);
+    b.remove_index(Axis(1), 0);
+    b.remove_index(Axis(2), 1);
+    assert_eq!(b, array![[[10], [20]], [[110], [120]]]);
+}
+
+#[test]
+fn test_remove_index_drops() {
+    use std::rc::Rc;
+
+    let elem = Rc::new(());
+    let mut a = Array::from_shape_fn((3, 2), |_| elem.clone());
+    a.remove_index(Axis(0), 0);
+    assert_eq!(Rc::strong_count(&elem), 5);
+    // removed elements that can't be compacted away are dropped with the array
+    a.remove_index(Axis(1), 1);
+    assert_eq!(a.len(), 2);
+    drop(a);
+    assert_eq!(Rc::strong_count(&elem), 1);
+}
+
 #[should_panic(expected="index out of bounds")]
 #[test]
 fn test_remove_index_oob3() {
     let mut a = array![[10], [4], [1]];
     a.remove_index(Axis(2), 0);
 }
+
+#[test]
+fn test_retain_axis() {
+    let mut a = Array::from_shape_fn((5, 3), |(i, j)| (10 * i + j) as i32);
+    a.retain_axis(Axis(0), |row| row[0] % 20 == 0).unwrap();
+    assert_eq!(a, array![[0, 1, 2], [20, 21, 22], [40, 41, 42]]);
+    assert!(a.is_standard_layout());
+    assert_eq!(
+        a.retain_axis(Axis(1), |_| true).unwrap_err().kind(),
+        ndarray::ErrorKind::IncompatibleLayout
+    );
+    assert_eq!(
+        a.retain_axis(Axis(2), |_| true).unwrap_err().kind(),
+        ndarray::ErrorKind::OutOfBounds
+    );
+    a.retain_axis(Axis(0), |_| false).unwrap();
+    assert_eq!(a.shape(), &[0, 3]);
+
+    // columns of a column major array, with subviews visited in order
+    let mut b = Array::from_shape_vec((2, 4).f(), (0..8).collect()).unwrap();
+    let mut seen = Vec::new();
+    b.retain_axis(Axis(1), |col| {
+        seen.push(col[0]);
+        col[0] != 2
+    })
+    .unwrap();
+    assert_eq!(seen, vec![0, 2, 4, 6]);
+    assert_eq!(b, array![[0, 4, 6], [1, 5, 7]]);
+
+    // a sliced array whose elements are still contiguous
+    let mut c = Array::from_shape_fn((4, 2, 2), |(i, j, k)| (100 * i + 10 * j + k) as i32);
+    c.slice_collapse(s![1.., .., ..]);
+    c.retain_axis(Axis(0), |sub| sub[[0, 0]] != 200).unwrap();
+    assert_eq!(c, array![[[100, 101], [110, 111]], [[300, 301], [310, 311]]]);
+}
+
+#[test]
+fn test_retain_axis_drops() {
+    use std::rc::Rc;
+
+    let elem = Rc::new(());
+    let mut a = Array::from_shape_fn((4, 2), |_| elem.clone());
+    let mut i = 0;
+    a.retain_axis(Axis(0), |_| {
+        i += 1;
+        i % 2 == 0
+    })
+    .unwrap();
+    assert_eq!(a.shape(), &[2, 2]);
+    assert_eq!(Rc::strong_count(&elem), 5);
+    drop(a);
+    assert_eq!(Rc::strong_count(&elem), 1);
+}
+
+#[test]
+fn test_pop_along() {
+    let mut a = Array::from_shape_vec((3, 2), (0..6).collect()).unwrap();
+    assert_eq!(a.pop_row(), Some(array![4, 5]));
+    assert_eq!(a.pop_row(), Some(array![2, 3]));
+    assert_eq!(a, array![[0, 1]]);
+    assert_eq!(a.pop_row(), Some(array![0, 1]));
+    assert_eq!(a.pop_row(), None);
+    assert_eq!(a.shape(), &[0, 2]);
+
+    // column major arrays pop along the last axis
+    let mut b = Array::from_shape_vec((2, 3).f(), (0..6).collect()).unwrap();
+    assert_eq!(b.pop_along(Axis(1)), Some(array![4, 5]));
+    assert_eq!(b, array![[0, 2], [1, 3]]);
+    assert!(b.t().is_standard_layout());
+
+    // the popped subview keeps the memory order of the other axes
+    let mut c = Array::from_shape_vec((2, 2, 3), (0..12).collect()).unwrap();
+    c.swap_axes(1, 2);
+    let lane = c.pop_along(Axis(0)).unwrap();
+    assert_eq!(lane, array![[6, 9], [7, 10], [8, 11]]);
+    assert!(lane.t().is_standard_layout());
+    assert_eq!(c, array![[[0, 3], [1, 4], [2, 5]]]);
+
+    // a sliced array only keeps its own elements
+    let mut d = Array::from_shape_vec(6, (0..6).collect()).unwrap();
+    d.slice_collapse(s![1..4]);
+    let ptr = d.as_ptr();
+    assert_eq!(d.pop_along(Axis(0)), Some(arr0(3)));
+    assert_eq!(d, array![1, 2]);
+    // the remaining elements are not moved
+    assert_eq!(d.as_ptr(), ptr);
+
+    let mut e = Array::from_shape_vec((3, 0), vec![]).unwrap();
+    assert_eq!(e.pop_row(), Some(Array1::<i32>::zeros(0)));
+    assert_eq!(e.shape(), &[2, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_pop_along_wrong_axis() {
+    let mut a = Array::<i32, _>::zeros((3, 2));
+    a.pop_along(Axis(1));
+}
+
+#[test]
+fn test_pop_along_drops() {
+    use std::rc::Rc;
+
+    let elem = Rc::new(());
+    let mut a = Array::from_shape_fn((3, 2), |_| elem.clone());
+    let row = a.pop_row().unwrap();
+    assert_eq!(Rc::strong_count(&elem), 7);
+    drop(row);
+    assert_eq!(Rc::strong_count(&elem), 5);
+    drop(a);
+    assert_eq!(Rc::strong_count(&elem), 1);
+}
+
+#[test]
+fn test_into_raw_vec_and_offset() {
+    let a = Array::from_shape_vec((3, 4), (0..12).collect()).unwrap();
+    let (v, offset) = a.clone().into_raw_vec_and_offset();
+    assert_eq!(v, (0..12).collect::<Vec<_>>());
+    assert_eq!(offset, Some(0));
+
+    let b = a.clone().slice_move(s![1.., 2..]);
+    let (v, offset) = b.into_raw_vec_and_offset();
+    assert_eq!(v.len(), 12);
+    assert_eq!(v[offset.unwrap()], 6);
+
+    // with negative strides, the first element is at the end of memory
+    let c = a.slice_move(s![..;-1, ..;-2]);
+    assert_eq!(c[[0, 0]], 11);
+    let (v, offset) = c.into_raw_vec_and_offset();
+    assert_eq!(offset, Some(11));
+    assert_eq!(v[11], 11);
+
+    let (v, offset) = Array::<i32, _>::zeros((2, 0)).into_raw_vec_and_offset();
+    assert_eq!((v.len(), offset), (0, None));
+    let (v, offset) = Array::from_elem(3, ()).slice_move(s![1..]).into_raw_vec_and_offset();
+    assert_eq!((v.len(), offset), (3, None));
+}
+
+#[test]
+fn test_try_into_scalar() {
+    assert_eq!(arr0(5).try_into_scalar(), Ok(5));
+    assert_eq!(array![[[7]]].try_into_scalar(), Ok(7));
+
+    let a = Array::from_shape_vec((3, 4), (0..12).collect()).unwrap();
+    let a = a.try_into_scalar().unwrap_err();
+    assert_eq!(a.shape(), &[3, 4]);
+    assert_eq!(a.slice_move(s![2.., 1..2]).try_into_scalar(), Ok(9));
+    assert!(Array::<i32, _>::zeros(0).try_into_scalar().is_err());
+
+    // elements are moved out, not cloned
+    let b = array![String::from("a"), String::from("b")].slice_move(s![1..]);
+    assert_eq!(b.into_dyn().try_into_scalar(), Ok(String::from("b")));
+
+    assert_eq!(Array::from_elem((1, 1), ()).try_into_scalar(), Ok(()));
+    let c = Array::from_elem(3, ()).slice_move(s![2..]);
+    assert_eq!(c.try_into_scalar(), Ok(()));
+    assert!(Array::from_elem(2, ()).try_into_scalar().is_err());
+}
+
+#[test]
+fn test_reserve() {
+    use ndarray::ErrorKind;
+
+    let mut a = Array::<i32, _>::zeros((0, 3));
+    a.reserve(Axis(0), 10).unwrap();
+    a.try_prepend_row(aview1(&[1, 2, 3])).unwrap();
+    let ptr = a.as_ptr();
+    for i in 0..9 {
+        a.try_prepend_row(aview1(&[i; 3])).unwrap();
+    }
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a.shape(), &[10, 3]);
+    assert_eq!(a.row(9), aview1(&[1, 2, 3]));
+
+    // the elements of a sliced array are found again after reallocating
+    let mut b = Array::from_shape_vec((4, 2), (0..8).collect()).unwrap();
+    b.slice_collapse(s![2.., ..]);
+    b.reserve_exact(Axis(0), 1000).unwrap();
+    assert_eq!(b, array![[4, 5], [6, 7]]);
+
+    let mut c = Array::from_shape_vec((2, 3).f(), (0..6).collect()).unwrap();
+    c.reserve(Axis(1), 5).unwrap();
+    assert_eq!(c, array![[0, 2, 4], [1, 3, 5]]);
+    assert_eq!(c.reserve(Axis(0), 5).unwrap_err().kind(), ErrorKind::IncompatibleLayout);
+    assert_eq!(c.reserve(Axis(2), 5).unwrap_err().kind(), ErrorKind::OutOfBounds);
+    assert_eq!(c.reserve(Axis(1), usize::MAX).unwrap_err().kind(), ErrorKind::Overflow);
+    assert_eq!(c.reserve(Axis(1), isize::MAX as usize / 4).unwrap_err().kind(), ErrorKind::Overflow);
+    c.reserve(Axis(0), 0).unwrap();
+
+    let mut d = Array::from_elem((2, 2), ());
+    d.reserve(Axis(0), 10).unwrap();
+}
+
+#[test]
+fn test_shrink_to_fit() {
+    let mut a = Array::from_shape_vec((3, 4), (0..12).collect()).unwrap();
+    a.shrink_to_fit();
+    assert_eq!(a, Array::from_shape_vec((3, 4), (0..12).collect()).unwrap());
+
+    let mut b = a.clone();
+    b.slice_collapse(s![..;-1, 1..3]);
+    let expected = b.to_owned();
+    b.shrink_to_fit();
+    assert_eq!(b, expected);
+    assert!(b.is_standard_layout());
+    assert_eq!(b.into_raw_vec(), vec![9, 10, 5, 6, 1, 2]);
+
+    let mut c = a.clone().reversed_axes();
+    c.shrink_to_fit();
+    assert!(c.is_standard_layout());
+    assert_eq!(c.row(0), aview1(&[0, 4, 8]));
+
+    let mut d = a.clone();
+    d.reserve(Axis(0), 100).unwrap();
+    d.shrink_to_fit();
+    assert_eq!(d, a);
+
+    let mut e = a;
+    e.slice_collapse(s![1..1, ..]);
+    e.shrink_to_fit();
+    assert_eq!(e.shape(), &[0, 4]);
+    assert_eq!(e.into_raw_vec().len(), 0);
+}
+
+#[test]
+fn test_shrink_to_fit_drops() {
+    use std::rc::Rc;
+
+    // not `Clone`, so the elements can only be moved
+    struct Counted(Rc<()>);
+
+    let elem = Rc::new(());
+    let mut a = Array::from_shape_fn((4, 5), |_| Counted(elem.clone()));
+    a.slice_collapse(s![1..3, ..;2]);
+    assert_eq!(Rc::strong_count(&elem), 21);
+    a.shrink_to_fit();
+    assert_eq!(a.shape(), &[2, 3]);
+    assert!(a.iter().all(|c| Rc::ptr_eq(&c.0, &elem)));
+    assert_eq!(Rc::strong_count(&elem), 7);
+    drop(a);
+    assert_eq!(Rc::strong_count(&elem), 1);
+
+    #[derive(Default)]
+    struct Zst;
+    let mut b = Array::from_elem(5, ()).slice_move(s![1..3]);
+    b.shrink_to_fit();
+    assert_eq!(b.into_raw_vec().len(), 2);
+    let mut c = Array::from_shape_simple_fn((2, 3), Zst::default);
+    c.slice_collapse(s![.., 1..]);
+    c.shrink_to_fit();
+    assert_eq!(c.into_raw_vec().len(), 4);
+}
+
+#[test]
+fn test_move_into() {
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let elem = Rc::new(());
+    let mut a = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j, elem.clone()));
+    a.slice_collapse(s![1..3, ..;-2]);
+    let mut b = Array::uninit((2, 3).f());
+    a.move_into(&mut b);
+    assert_eq!(Rc::strong_count(&elem), 7);
+    let b = unsafe { b.assume_init() };
+    assert_eq!(b.map(|&(x, _)| x), array![[9, 7, 5], [14, 12, 10]]);
+    drop(b);
+    assert_eq!(Rc::strong_count(&elem), 1);
+
+    // zero-sized elements
+    let c = Array::from_elem(6, ()).slice_move(s![..;3]);
+    let mut d = Array::uninit(2);
+    c.move_into(d.view_mut());
+    assert_eq!(unsafe { d.assume_init() }, array![(), ()]);
+
+    // zero-sized elements that need to be dropped
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Zst;
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    let e = Array::from_shape_simple_fn(6, || Zst).slice_move(s![..;3]);
+    let mut f = Array::uninit(2);
+    e.move_into(&mut f);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 4);
+    drop(unsafe { f.assume_init() });
+    assert_eq!(DROPS.load(Ordering::SeqCst), 6);
+}
+
+#[test]
+#[should_panic]
+fn test_move_into_wrong_shape() {
+    let mut b = Array::uninit((2, 3));
+    Array::<i32, _>::zeros((3, 2)).move_into(&mut b);
+}
+
+#[test]
+fn debug_assert_invariants_valid() {
+    let a = array![[0, 1, 2], [3, 4, 5]];
+    a.debug_assert_invariants();
+    a.t().debug_assert_invariants();
+    a.slice(s![..;-1, 1..;-2]).debug_assert_invariants();
+    a.clone().slice_move(s![1.., ..;-1]).debug_assert_invariants();
+    a.into_shared().reversed_axes().debug_assert_invariants();
+    Array2::<i32>::zeros((0, 3)).debug_assert_invariants();
+    Array2::<()>::default((3, 3)).debug_assert_invariants();
+}
@@ -0,0 +1,43 @@
+use ndarray::prelude::*;
+
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn fetch_add_at_threads() {
+    let n_threads = 4;
+    let n_iter = 1000;
+    let acc = Arc::new(Array::from_shape_simple_fn((2, 3), || AtomicUsize::new(0)));
+    let handles: Vec<_> = (0..n_threads)
+        .map(|t| {
+            let acc = acc.clone();
+            thread::spawn(move || {
+                for i in 0..n_iter {
+                    acc.fetch_add_at((i % 2, (i + t) % 3), 1);
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+    let counts = acc.map(|x| x.load(Ordering::Relaxed));
+    assert_eq!(counts.sum(), n_threads * n_iter);
+    assert_eq!(counts.sum_axis(Axis(1)), array![n_threads * n_iter / 2, n_threads * n_iter / 2]);
+}
+
+#[test]
+fn fetch_add_at_view() {
+    let a = Array::from_shape_simple_fn(3, || AtomicU32::new(5));
+    let v = a.slice(s![1..]);
+    assert_eq!(v.fetch_add_at(1, 10), 5);
+    assert_eq!(a[2].load(Ordering::Relaxed), 15);
+}
+
+#[test]
+#[should_panic]
+fn fetch_add_at_out_of_bounds() {
+    let a = Array::from_shape_simple_fn(3, || AtomicUsize::new(0));
+    a.fetch_add_at(3, 1);
+}
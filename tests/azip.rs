@@ -455,3 +455,45 @@ fn test_zip_all_empty_array() {
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| true));
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| false));
 }
+
+#[test]
+fn test_zip_try_fold() {
+    let a = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as i32);
+    let b = a.t().to_owned();
+    let sum = Zip::from(&a).and(b.t()).try_fold(0, |acc, &x, &y| Ok::<_, ()>(acc + x * y));
+    assert_eq!(sum, Ok((&a * &a).sum()));
+
+    // stops at the first error, in the same order as `fold`
+    let mut visited = Vec::new();
+    let res = Zip::from(&a).try_fold(0, |acc, &x| {
+        visited.push(x);
+        if x >= 7 { Err(x) } else { Ok(acc + x) }
+    });
+    assert_eq!(res, Err(7));
+    assert_eq!(visited, (0..8).collect::<Vec<_>>());
+
+    let empty = Array::<i32, _>::zeros((0, 3));
+    assert_eq!(Zip::from(&empty).try_fold(5, |_, _| Err(())), Ok(5));
+}
+
+#[test]
+fn test_zip_try_for_each() {
+    let a = Array::from_shape_fn((3, 3), |(i, j)| (i * 3 + j) as u8 * 30);
+    let b = Array::from_elem((3, 3), 20u8);
+    let mut c = Array::zeros((3, 3));
+    let res = Zip::from(&mut c).and(&a).and(&b).try_for_each(|c, &x, &y| {
+        *c = x.checked_add(y).ok_or((x, y))?;
+        Ok(())
+    });
+    // 240 + 20 overflows at the last element, which is left unchanged
+    assert_eq!(res, Err((240, 20)));
+    assert_eq!(c.slice(s![..2, ..]), a.slice(s![..2, ..]).mapv(|x| x + 20));
+    assert_eq!(c.row(2), aview1(&[200, 230, 0]));
+
+    let mut count = 0;
+    let res: Result<(), ()> = Zip::from(&a).and(&b).try_for_each(|_, _| {
+        count += 1;
+        Ok(())
+    });
+    assert_eq!((res, count), (Ok(()), 9));
+}
@@ -0,0 +1,61 @@
+use ndarray::prelude::*;
+use ndarray::{Backend, BackendArray, BackendMut, ErrorKind, ShapeBuilder};
+
+/// A trivial storage backend that keeps its elements in a `Vec`.
+#[derive(Clone, Debug)]
+struct VecBackend<A>(Vec<A>);
+
+unsafe impl<A> Backend for VecBackend<A> {
+    type Elem = A;
+
+    fn as_ptr(&self) -> *const A {
+        self.0.as_ptr()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+unsafe impl<A> BackendMut for VecBackend<A> {}
+
+#[test]
+fn backend_read_ops() {
+    let a = BackendArray::from_backend((2, 3), VecBackend(vec![1, 2, 3, 4, 5, 6])).unwrap();
+    assert_eq!(a.shape(), &[2, 3]);
+    assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.sum(), 21);
+    assert_eq!(a.t().row(0), array![1, 4]);
+    assert_eq!(a.slice(s![.., 1..]), array![[2, 3], [5, 6]]);
+    assert_eq!(&a + &a, array![[2, 4, 6], [8, 10, 12]]);
+    assert_eq!(a.backend().0.len(), 6);
+    assert_eq!(a.to_owned(), array![[1, 2, 3], [4, 5, 6]]);
+}
+
+#[test]
+fn backend_write_ops() {
+    let mut a = BackendArray::from_backend((2, 2).f(), VecBackend(vec![1., 2., 3., 4.])).unwrap();
+    assert_eq!(a, array![[1., 3.], [2., 4.]]);
+    a += 1.;
+    a.row_mut(0).fill(0.);
+    assert_eq!(a, array![[0., 0.], [3., 5.]]);
+    assert_eq!(a.backend().0, vec![0., 3., 0., 5.]);
+}
+
+#[test]
+fn backend_clone() {
+    let a = BackendArray::from_backend(4, VecBackend(vec![1, 2, 3, 4])).unwrap();
+    let b = a.slice_move(s![2..]);
+    let mut c = b.clone();
+    c[0] = 10;
+    assert_eq!(b, array![3, 4]);
+    assert_eq!(c, array![10, 4]);
+}
+
+#[test]
+fn backend_shape_errors() {
+    let res = BackendArray::from_backend((2, 2), VecBackend(vec![1, 2, 3]));
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::OutOfBounds);
+    let res = BackendArray::from_backend((1, 2), VecBackend(vec![1, 2, 3]));
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+}
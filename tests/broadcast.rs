@@ -48,6 +48,37 @@ fn test_add_incompat() {
     a += &incompat;
 }
 
+#[test]
+fn test_assign_ops_broadcast_row() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let bias = Array::from(vec![10, 20, 30, 40]);
+    a += &bias;
+    assert_eq!(a, Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32 + 10 * (j + 1) as i32));
+    a -= &bias.view();
+    a *= &bias.slice(s![..1]);
+    assert_eq!(a, Array::from_shape_fn((3, 4), |(i, j)| 10 * (i * 4 + j) as i32));
+
+    // broadcast a column
+    let col = Array::from_shape_vec((3, 1), vec![1, 2, 3]).unwrap();
+    a /= &col;
+    assert_eq!(a.column(0), Array::from(vec![0, 20, 26]));
+}
+
+#[test]
+fn test_assign_ops_broadcast_scalar_shaped() {
+    let mut a = Array::from_elem((2, 3), 1.);
+    a += &Array::from_elem((1, 1), 2.);
+    a *= &Array::from_elem((), 3.);
+    assert_eq!(a, Array::from_elem((2, 3), 9.));
+}
+
+#[test]
+#[should_panic(expected = "could not broadcast array from shape: [3] to: [2, 4]")]
+fn test_assign_ops_broadcast_incompat_message() {
+    let mut a = Array::<f32, _>::zeros((2, 4));
+    a += &Array::zeros(3);
+}
+
 #[test]
 fn test_broadcast() {
     let (_, n, k) = (16, 16, 16);
@@ -81,3 +112,53 @@ fn test_broadcast_1d() {
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn test_expand_to() {
+    let a = arr2(&[[1, 2, 3, 4]]);
+    let mut e = a.expand_to((3, 4)).unwrap();
+    assert_eq!(e, arr2(&[[1, 2, 3, 4], [1, 2, 3, 4], [1, 2, 3, 4]]));
+    assert!(e.is_standard_layout());
+    assert_eq!(e.strides(), &[4, 1]);
+
+    // the rows no longer alias each other
+    e[[0, 0]] = 10;
+    assert_eq!(e.column(0), arr1(&[10, 1, 1]));
+
+    let e = a.t().expand_to((2, 4, 1)).unwrap();
+    assert_eq!(e.shape(), &[2, 4, 1]);
+    assert!(e.is_standard_layout());
+    assert_eq!(e.slice(s![1, .., 0]), arr1(&[1, 2, 3, 4]));
+
+    let err = a.expand_to((3, 5)).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::IncompatibleShape);
+    assert!(a.expand_to(4).is_err());
+}
+
+#[test]
+fn test_broadcasted_apply() {
+    let a = Array::from_shape_fn((64, 32), |(i, j)| (i * 32 + j) as f64);
+    let bias = Array::from_shape_fn(32, |j| j as f64 * 0.5);
+    let res = a.broadcasted_apply(&bias, |x, b| x + b);
+    assert_eq!(res, &a + &bias);
+    assert_eq!(res.shape(), a.shape());
+
+    // `other` is visited in place through its broadcast view
+    let column = Array::from_shape_fn((64, 1), |(i, _)| i);
+    let ptrs = a.broadcasted_apply(&column, |_, c| c as *const usize);
+    let base = column.as_ptr();
+    for ((i, _), &p) in ptrs.indexed_iter() {
+        assert_eq!(p, base.wrapping_add(i));
+    }
+
+    // a scalar-shaped array and a view as the other operand
+    let s = arr0(2.);
+    assert_eq!(a.t().broadcasted_apply(&s.view(), |x, y| x * y), a.t().mapv(|x| x * 2.));
+}
+
+#[test]
+#[should_panic(expected = "could not broadcast array from shape: [3] to: [2, 4]")]
+fn test_broadcasted_apply_incompat() {
+    let a = Array::<f32, _>::zeros((2, 4));
+    a.broadcasted_apply(&Array::<f32, _>::zeros(3), |x, y| x + y);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "bytemuck")]
+
+use ndarray::prelude::*;
+
+#[test]
+fn view_bytes_as_roundtrip() {
+    let a: Array1<u32> = array![1, 0x0100, 0x0001_0000, 0xff00_0000];
+    let bytes = a.view_bytes_as::<u8>().unwrap();
+    assert_eq!(bytes.len(), 16);
+    let expected: Vec<u8> = a.iter().flat_map(|x| x.to_ne_bytes().to_vec()).collect();
+    assert_eq!(bytes.to_vec(), expected);
+    assert_eq!(bytes.view_bytes_as::<u32>().unwrap(), a);
+}
+
+#[test]
+fn view_bytes_as_layouts() {
+    let a = Array::from_shape_vec((2, 2), vec![1u16, 2, 3, 4]).unwrap();
+    assert_eq!(a.view_bytes_as::<u16>().unwrap(), array![1, 2, 3, 4]);
+    // Fortran layout is read in memory order
+    assert_eq!(a.t().view_bytes_as::<u16>().unwrap(), array![1, 2, 3, 4]);
+    assert_eq!(a.slice(s![.., ..1]).view_bytes_as::<u16>(), None);
+}
+
+#[test]
+fn view_bytes_as_failures() {
+    let a: Array1<u32> = array![1, 2, 3];
+    let bytes = a.view_bytes_as::<u8>().unwrap();
+    // Misaligned start
+    assert_eq!(bytes.slice(s![1..5]).view_bytes_as::<u32>(), None);
+    // Length not a multiple of the element size
+    assert_eq!(bytes.slice(s![..6]).view_bytes_as::<u32>(), None);
+    assert_eq!(bytes.slice(s![..8]).view_bytes_as::<u32>().unwrap(), array![1, 2]);
+}
@@ -171,3 +171,124 @@ fn test_into_dimension() {
     let c = a2.clone().into_dimensionality::<IxDyn>().unwrap();
     assert_eq!(a2, c);
 }
+
+#[test]
+fn test_into_dimensionality_error() {
+    use ndarray::{ErrorKind, Ix2, IxDyn};
+
+    let a = Array::<f64, _>::zeros(IxDyn(&[2, 3, 4]));
+    let err = a.into_dimensionality::<Ix2>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let message = err.to_string();
+    assert!(message.contains("expected 2 axes, got 3"), "{}", message);
+
+    let b = Array::<f64, _>::zeros((2, 3));
+    let err = b.view().into_dimensionality::<Ix3>().unwrap_err();
+    assert!(format!("{:?}", err).contains("expected 3 axes, got 2"));
+
+    // other errors have no detail
+    let err = Array::from_shape_vec((2, 2), vec![1]).unwrap_err();
+    assert_eq!(err.to_string(), "ShapeError/OutOfBounds: out of bounds indexing");
+}
+
+#[test]
+fn test_as_dyn() {
+    use ndarray::{arr2, ArrayViewD, ArrayViewMutD};
+
+    fn sum_all(a: ArrayViewD<'_, i32>) -> i32 {
+        a.sum()
+    }
+
+    fn double_all(mut a: ArrayViewMutD<'_, i32>) {
+        a *= 2;
+    }
+
+    let mut a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(sum_all(a.as_dyn()), 21);
+    assert_eq!(a.as_dyn().shape(), a.shape());
+    assert_eq!(a.as_dyn().as_ptr(), a.as_ptr());
+
+    // strides are kept
+    let t = a.t();
+    assert_eq!(t.as_dyn().strides(), t.strides());
+    assert_eq!(t.as_dyn(), t.into_dyn());
+
+    double_all(a.as_dyn_mut());
+    assert_eq!(a, arr2(&[[2, 4, 6], [8, 10, 12]]));
+    double_all(a.slice_mut(ndarray::s![.., 1]).as_dyn_mut());
+    assert_eq!(a, arr2(&[[2, 8, 6], [8, 20, 12]]));
+}
+
+#[test]
+fn test_reshape_dyn() {
+    use ndarray::{ErrorKind, IxDyn};
+
+    let a = Array::from_shape_vec(IxDyn(&[4, 6]), (0..24).collect()).unwrap();
+    let b = a.reshape_dyn(&[2, 3, 4]).unwrap();
+    assert_eq!(b.shape(), &[2, 3, 4]);
+    // contiguous input is borrowed, not copied
+    assert!(b.is_view());
+    assert_eq!(b.as_ptr(), a.as_ptr());
+    assert_eq!(b.iter().cloned().collect::<Vec<_>>(), (0..24).collect::<Vec<_>>());
+    assert_eq!(b[[1, 2, 3]], 23);
+
+    // non-contiguous input is copied in logical order
+    let t = a.t();
+    let c = t.reshape_dyn(&[3, 2, 4]).unwrap();
+    assert!(c.is_owned());
+    assert_eq!(c.iter().collect::<Vec<_>>(), t.iter().collect::<Vec<_>>());
+
+    let err = a.reshape_dyn(&[5, 5]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert!(a.reshape_dyn(&[2, 3]).is_err());
+}
+
+#[test]
+fn test_reshape_infer() {
+    use ndarray::{ErrorKind, IxDyn};
+
+    let a = Array::from_shape_vec(IxDyn(&[4, 6]), (0..24).collect()).unwrap();
+    let b = a.reshape_infer(&[2, -1, 4]).unwrap();
+    assert_eq!(b.shape(), &[2, 3, 4]);
+    assert_eq!(b, a.reshape_dyn(&[2, 3, 4]).unwrap());
+    assert_eq!(a.reshape_infer(&[-1]).unwrap().shape(), &[24]);
+    assert_eq!(a.reshape_infer(&[3, 8]).unwrap().shape(), &[3, 8]);
+
+    let err = a.reshape_infer(&[-1, 2, -1]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = a.reshape_infer(&[5, -1]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert!(a.reshape_infer(&[0, -1]).is_err());
+    assert!(a.reshape_infer(&[-2, -12]).is_err());
+    assert!(a.reshape_infer(&[5, 5]).is_err());
+}
+
+#[test]
+fn test_permute_axes_all() {
+    let a = Array::from_shape_vec((2, 3, 4), (0..24).collect()).unwrap();
+    let views = a.permute_axes_all();
+    assert_eq!(views.len(), 6);
+    let shapes: Vec<_> = views.iter().map(|v| v.shape().to_vec()).collect();
+    assert_eq!(
+        shapes,
+        vec![
+            vec![2, 3, 4],
+            vec![2, 4, 3],
+            vec![3, 2, 4],
+            vec![3, 4, 2],
+            vec![4, 2, 3],
+            vec![4, 3, 2],
+        ]
+    );
+    for v in &views {
+        assert_eq!(v.as_ptr(), a.as_ptr());
+        assert_eq!(v.sum(), a.sum());
+    }
+    assert_eq!(views[0], a.view().into_dyn());
+    assert_eq!(views[5], a.t().into_dyn());
+    assert_eq!(views[3], a.view().permuted_axes([1, 2, 0]).into_dyn());
+
+    // zero-dimensional arrays have one (empty) permutation
+    assert_eq!(ndarray::arr0(1.).permute_axes_all().len(), 1);
+    assert_eq!(ndarray::arr1(&[1, 2]).permute_axes_all().len(), 1);
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "mmap")]
+
+use ndarray::prelude::*;
+use ndarray::MmapArray;
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+fn write_file(name: &str, header: &[u8], data: &[f64]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("ndarray-test-{}-{}", std::process::id(), name));
+    let mut file = fs::File::create(&path).unwrap();
+    file.write_all(header).unwrap();
+    for x in data {
+        file.write_all(&x.to_ne_bytes()).unwrap();
+    }
+    path
+}
+
+#[test]
+fn mmap_2d() {
+    let data = [1., 2., 3., 4., 5., 6.];
+    let path = write_file("2d", &[0; 8], &data);
+    let a = unsafe { MmapArray::<f64, Ix2>::from_mmap(&path, (2, 3), 8).unwrap() };
+    let v = a.view();
+    assert_eq!(v, array![[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(v.t().row(2), array![3., 6.]);
+    assert_eq!(a.sum_axis(Axis(0)), array![5., 7., 9.]);
+    drop(a);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_errors() {
+    let path = write_file("errors", &[0; 8], &[1., 2., 3., 4.]);
+    unsafe {
+        let res = MmapArray::<f64, Ix2>::from_mmap(&path, (2, 3), 8);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let res = MmapArray::<f64, Ix1>::from_mmap(&path, 4, 4);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let res = MmapArray::<f64, Ix1>::from_mmap(&path, 0, 48);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let res = MmapArray::<f64, Ix1>::from_mmap(path.with_extension("missing"), 0, 0);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_trailing_bytes() {
+    // Two elements followed by half of a third
+    let path = write_file("trailing", &[0; 8], &[1., 2.]);
+    fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(&[0; 4]).unwrap();
+    let res = unsafe { MmapArray::<f64, Ix1>::from_mmap(&path, 2, 8) };
+    assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    fs::remove_file(&path).unwrap();
+}
@@ -7,9 +7,14 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{arr0, arr1, arr2, array, aview1, confusion_matrix, polyval, s, Array, Array1, Array2, Array3, Axis};
+use ndarray::{accuracy, precision_recall_f1};
+use ndarray::{auc, mae, mse, r2_score, roc_curve};
 use std::f64;
 
+#[cfg(feature = "std")]
+use ndarray::{cdist, cross_entropy, cross_entropy_soft, polyfit, rmse, Metric, MinMaxScaler, StandardScaler};
+
 #[test]
 fn test_mean_with_nan_values() {
     let a = array![f64::NAN, 1.];
@@ -38,6 +43,221 @@ fn test_mean_with_array_of_floats() {
     assert_abs_diff_eq!(a.mean().unwrap(), exact_mean);
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn whole_array_reductions_2d() {
+    let a: Array2<f64> = arr2(&[[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(a.mean(), Some(3.5));
+    assert_eq!(a.t().mean(), Some(3.5));
+    assert_eq!(a.product(), 720.);
+    assert_abs_diff_eq!(a.var(0.), 35. / 12., epsilon = 1e-12);
+    assert_abs_diff_eq!(a.var(1.), 3.5, epsilon = 1e-12);
+    assert_abs_diff_eq!(a.std(0.), (35f64 / 12.).sqrt(), epsilon = 1e-12);
+    assert_abs_diff_eq!(a.slice(s![.., ..;2]).std(1.), a.slice(s![.., ..;2]).var(1.).sqrt());
+    assert_eq!(Array2::<f64>::zeros((0, 3)).mean(), None);
+}
+
+#[test]
+fn all_any() {
+    let a = arr2(&[[true, true, true], [true, false, true]]);
+    assert!(!a.all());
+    assert!(a.any());
+    assert!(a.row(0).all());
+    assert!(a.t().row(0).all());
+    assert!(!a.column(1).all());
+    assert!(!a.mapv(|x| !x).row(0).any());
+    assert!(a.slice(s![.., ..;2]).all());
+
+    // short-circuits on large arrays
+    let mut big = Array::from_elem(1 << 20, true);
+    big[1] = false;
+    assert!(!big.all());
+    assert!(big.any());
+
+    let empty = Array2::<bool>::from_elem((0, 3), false);
+    assert!(empty.all());
+    assert!(!empty.any());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn median_axis() {
+    use ndarray::ArrayView1;
+
+    fn manual_median(lane: ArrayView1<f64>) -> f64 {
+        let mut v = lane.to_vec();
+        v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = v.len();
+        if n % 2 == 1 {
+            v[n / 2]
+        } else {
+            (v[n / 2 - 1] + v[n / 2]) / 2.
+        }
+    }
+
+    // odd lengths along axis 1, even along axis 0
+    let a = Array::from_shape_fn((4, 5), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 4.);
+    for &axis in &[Axis(0), Axis(1)] {
+        let expected = a.map_axis(axis, manual_median);
+        assert_eq!(a.median_axis(axis), expected);
+        assert_eq!(a.t().median_axis(Axis(1 - axis.index())), expected);
+    }
+    assert_eq!(arr1(&[2., 1.]).median_axis(Axis(0)), arr0(1.5));
+    assert_eq!(arr1(&[7.]).median_axis(Axis(0)), arr0(7.));
+
+    let b = arr2(&[[1., f64::NAN, 3.], [1., 2., 3.]]);
+    let m = b.median_axis(Axis(1));
+    assert!(m[0].is_nan());
+    assert_eq!(m[1], 2.);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn median_axis_empty() {
+    Array2::<f64>::zeros((0, 2)).median_axis(Axis(0));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn describe() {
+    let a = arr2(&[[2., 8., 5.], [1., 9., 4.], [7., 3., 6.]]);
+    let summary = a.describe();
+    assert_eq!(summary.count, 9);
+    assert_eq!(summary.mean, a.mean().unwrap());
+    assert_eq!(summary.std, a.std(1.));
+    assert_eq!(summary.min, 1.);
+    assert_eq!(summary.max, 9.);
+    assert_eq!(summary.median, 5.);
+    assert_eq!(summary.median, a.clone().into_shape(9).unwrap().median_axis(Axis(0))[()]);
+    assert_eq!(summary.q25, 3.);
+    assert_eq!(summary.q75, 7.);
+    assert_eq!(a.t().describe(), summary);
+
+    // interpolated percentiles
+    let b = arr1(&[1., 2., 3., 4.]);
+    let summary = b.describe();
+    assert_eq!(summary.q25, 1.75);
+    assert_eq!(summary.median, 2.5);
+    assert_eq!(summary.q75, 3.25);
+
+    let empty = Array1::<f64>::zeros(0).describe();
+    assert_eq!(empty.count, 0);
+    assert!(empty.mean.is_nan() && empty.min.is_nan() && empty.max.is_nan());
+    let nan = arr1(&[1., f64::NAN]).describe();
+    assert_eq!(nan.count, 2);
+    assert!(nan.median.is_nan());
+    let single = arr1(&[3f64]).describe();
+    assert_eq!((single.min, single.median, single.max), (3., 3., 3.));
+    assert!(single.std.is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn winsorize() {
+    let mut a = Array::linspace(0., 100., 101).into_shape((101, 1)).unwrap();
+    a[[3, 0]] = -1e6;
+    a[[50, 0]] = 1e6;
+    let summary = a.describe();
+    a.winsorize(0.05, 0.95);
+    assert_eq!(a[[3, 0]], 5.);
+    assert_eq!(a[[50, 0]], 96.);
+    assert_eq!(a.iter().cloned().fold(f64::INFINITY, f64::min), 5.);
+    assert_eq!(a.iter().cloned().fold(f64::NEG_INFINITY, f64::max), 96.);
+    assert_eq!(a[[10, 0]], 10.);
+    assert_eq!(a.describe().median, summary.median);
+
+    // interpolated bounds, NaN is left alone
+    let mut b = arr2(&[[0., 10.], [f64::NAN, 20.]]);
+    b.winsorize(0.25, 0.75);
+    assert_eq!(b[[0, 0]], 5.);
+    assert_eq!(b[[0, 1]], 10.);
+    assert_eq!(b[[1, 1]], 15.);
+    assert!(b[[1, 0]].is_nan());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn winsorize_bad_quantiles() {
+    arr1(&[1., 2.]).winsorize(0.9, 0.1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn rescale() {
+    let a = arr1(&[0., 5., 10.]);
+    assert_eq!(a.rescaled(0., 1.), arr1(&[0., 0.5, 1.]));
+    assert_eq!(a.rescaled(1., -1.), arr1(&[1., 0., -1.]));
+
+    let mut b = arr2(&[[3., 7.], [5., f64::NAN]]);
+    b.rescale(0., 100.);
+    assert_eq!(b.slice(s![.., 0]), arr1(&[0., 50.]));
+    assert_eq!(b[[0, 1]], 100.);
+    assert!(b[[1, 1]].is_nan());
+
+    let mut c = Array::from_elem((2, 2), 4.);
+    c.rescale(-1., 1.);
+    assert_eq!(c, Array::from_elem((2, 2), -1.));
+
+    let mut empty = Array1::<f64>::zeros(0);
+    empty.rescale(0., 1.);
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn robust_scale() {
+    let a = arr2(&[[1., 10.], [2., 10.], [3., 10.], [4., 10.], [1000., 11.]]);
+    let mut b = a.clone();
+    b.robust_scale(Axis(0));
+
+    // first column: median 3, quartiles 2 and 4
+    let expected = a.column(0).mapv(|x| (x - 3.) / 2.);
+    assert_eq!(b.column(0), expected);
+    // second column has zero IQR: centered only
+    assert_eq!(b.column(1), arr1(&[0., 0., 0., 0., 1.]));
+
+    let mut t = a.t().to_owned();
+    t.robust_scale(Axis(1));
+    assert_eq!(t, b.t());
+
+    let mut c = arr1(&[f64::NAN, 1., 3.]);
+    c.robust_scale(Axis(0));
+    assert!(c[0].is_nan());
+    assert_eq!(c.slice(s![1..]), arr1(&[-1., 1.]));
+}
+
+#[test]
+fn cumsum_bool_axis() {
+    let a = arr1(&[true, false, true, true]);
+    assert_eq!(a.cumsum_bool_axis(Axis(0)), arr1(&[1, 1, 2, 3]));
+
+    let b = arr2(&[[true, false, true], [false, true, true]]);
+    assert_eq!(b.cumsum_bool_axis(Axis(0)), arr2(&[[1, 0, 1], [1, 1, 2]]));
+    assert_eq!(b.cumsum_bool_axis(Axis(1)), arr2(&[[1, 1, 2], [0, 1, 2]]));
+    assert_eq!(b.t().cumsum_bool_axis(Axis(0)), b.cumsum_bool_axis(Axis(1)).t());
+
+    let empty = Array2::<bool>::from_elem((0, 2), true);
+    assert_eq!(empty.cumsum_bool_axis(Axis(0)).shape(), &[0, 2]);
+}
+
+#[test]
+fn count_nonzero() {
+    let a = arr2(&[[1, 0, 0, 4], [0, 0, 0, 0], [5, 6, 7, 0]]);
+    assert_eq!(a.count_nonzero(), 5);
+    assert_eq!(a.count_nonzero_axis(Axis(1)), arr1(&[2, 0, 3]));
+    assert_eq!(a.count_nonzero_axis(Axis(0)), arr1(&[2, 1, 1, 1]));
+    assert_eq!(a.t().count_nonzero_axis(Axis(0)), arr1(&[2, 0, 3]));
+
+    let f = arr1(&[0., -0., f64::NAN, 1e-300]);
+    assert_eq!(f.count_nonzero(), 2);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.count_nonzero(), 0);
+    assert_eq!(empty.count_nonzero_axis(Axis(0)), arr1(&[0, 0, 0]));
+}
+
 #[test]
 fn sum_mean() {
     let a: Array2<f64> = arr2(&[[1., 2.], [3., 4.]]);
@@ -307,3 +527,706 @@ fn std_axis_empty_axis() {
     assert_eq!(v.shape(), &[2]);
     v.mapv(|x| assert!(x.is_nan()));
 }
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn polyfit_quadratic_exact() {
+    let x = array![-2., -1., 0., 0.5, 1., 3.];
+    let y = x.mapv(|x: f64| 0.5 * x * x - 2. * x + 3.);
+    let coeffs = polyfit(&x, &y, 2);
+    assert_abs_diff_eq!(coeffs, array![0.5, -2., 3.], epsilon = 1e-10);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn polyfit_linear() {
+    let x = Array::linspace(0., 10., 11);
+    let y = x.mapv(|x| 4. * x - 1.);
+    let coeffs = polyfit(&x.view(), &y.view(), 1);
+    assert_abs_diff_eq!(coeffs, array![4., -1.], epsilon = 1e-10);
+
+    // least squares through points that are not on a line
+    let x = array![0., 1., 2., 3.];
+    let y = array![1., 3., 2., 4.];
+    let coeffs = polyfit(&x, &y, 1);
+    assert_abs_diff_eq!(coeffs, array![0.8, 1.3], epsilon = 1e-10);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn polyfit_too_few_points() {
+    let x = array![0., 1.];
+    polyfit(&x, &x, 2);
+}
+
+#[test]
+fn polyval_horner() {
+    let p = array![1., 0., -1.];
+    assert_eq!(polyval(&p, &array![-1., 0., 0.5, 2.]), array![0., -1., -0.75, 3.]);
+    assert_eq!(
+        polyval(&p.view(), &array![[1., 2.], [3., 4.]].t()),
+        array![[0., 8.], [3., 15.]]
+    );
+    assert_eq!(polyval(&array![7.], &array![1., 2.]), array![7., 7.]);
+    let empty: Array1<f64> = array![];
+    assert_eq!(polyval(&empty, &array![1., 2.]), array![0., 0.]);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn polyval_polyfit_round_trip() {
+    let x = array![0., 1., 2., 3., 4.];
+    let y = array![1., 2., 5., 10., 17.];
+    assert_abs_diff_eq!(polyval(&polyfit(&x, &y, 2), &x), y, epsilon = 1e-10);
+}
+
+#[test]
+fn checked_arithmetic() {
+    let a = array![[100i8, -100], [50, 0]];
+    let b = array![[27i8, -28], [-50, 127]];
+    assert_eq!(a.checked_add(&b), Some(array![[127, -128], [0, 127]]));
+    assert_eq!(a.checked_add(&array![28i8, 0]), None);
+    assert_eq!(a.checked_sub(&array![[-27i8], [-77]]), Some(array![[127, -73], [127, 77]]));
+    assert_eq!(a.checked_sub(&array![0i8, 29]), None);
+    assert_eq!(a.checked_mul(&arr0(1i8)), Some(a.clone()));
+    assert_eq!(a.checked_mul(&arr0(2i8)), None);
+    assert_eq!(a.t().checked_mul(&array![1i8, 2]), Some(array![[100, 100], [-100, 0]]));
+}
+
+#[test]
+#[should_panic]
+fn checked_add_broadcast_fail() {
+    let a = array![[1i32, 2], [3, 4]];
+    let _ = a.checked_add(&array![1i32, 2, 3]);
+}
+
+#[test]
+fn saturating_wrapping_arithmetic() {
+    let a = array![[0u8, 100], [200, 255]];
+    let b = array![[1u8, 155], [56, 255]];
+    assert_eq!(a.saturating_add(&b), array![[1, 255], [255, 255]]);
+    assert_eq!(a.wrapping_add(&b), array![[1, 255], [0, 254]]);
+    assert_eq!(a.saturating_sub(&array![1u8, 101]), array![[0, 0], [199, 154]]);
+    assert_eq!(a.wrapping_sub(&array![1u8, 101]), array![[255, 255], [199, 154]]);
+    assert_eq!(a.saturating_mul(&array![[2u8], [1]]), array![[0, 200], [200, 255]]);
+    assert_eq!(a.wrapping_mul(&array![[2u8], [1]]), array![[0, 200], [200, 255]]);
+    assert_eq!(a.t().wrapping_mul(&arr0(3u8)), array![[0, 88], [44, 253]]);
+    assert_eq!(array![i16::MIN, -1].saturating_sub(&arr0(1i16)), array![i16::MIN, -2]);
+}
+
+#[test]
+fn abs_diff() {
+    let a = array![[1., -3.], [2.5, f64::INFINITY]];
+    let b = array![[4., -1.], [2.5, 0.]];
+    assert_eq!(a.abs_diff(&b), array![[3., 2.], [0., f64::INFINITY]]);
+    assert_eq!(a.abs_diff(&b), b.abs_diff(&a));
+    assert!(a.abs_diff(&arr0(f64::NAN)).iter().all(|x| x.is_nan()));
+
+    let c = array![[0u32, 10], [u32::MAX, 3]];
+    assert_eq!(c.abs_diff(&array![5u32, 4]), array![[5, 6], [u32::MAX - 5, 1]]);
+    assert_eq!(c.t().abs_diff(&arr0(1u32)), array![[1, u32::MAX - 1], [9, 2]]);
+    assert_eq!(array![-5i32, 5].abs_diff(&arr0(-7)), array![2, 12]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn hypot() {
+    let a = array![[3., 6.], [-5., 0.]];
+    let b = array![[4., 8.], [12., -2.]];
+    assert_eq!(a.hypot(&b), array![[5., 10.], [13., 2.]]);
+    assert_eq!(a.t().hypot(&array![[4.], [8.]]), array![[5., 4f64.hypot(5.)], [10., 8.]]);
+    // no overflow in the intermediate squares
+    let c = array![3e200, 3e-200].hypot(&array![4e200, 4e-200]);
+    assert_abs_diff_eq!(c[0] / 5e200, 1., epsilon = 1e-15);
+    assert_abs_diff_eq!(c[1] / 5e-200, 1., epsilon = 1e-15);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn atan2() {
+    use std::f64::consts::PI;
+
+    // one point in each quadrant, and on each half axis
+    let y = array![1., 1., -1., -1., 0., 2., 0., -2.];
+    let x = array![1., -1., -1., 1., 3., 0., -3., 0.];
+    let expected = array![PI / 4., 3. * PI / 4., -3. * PI / 4., -PI / 4., 0., PI / 2., PI, -PI / 2.];
+    assert_abs_diff_eq!(y.atan2(&x), expected, epsilon = 1e-15);
+
+    let y = array![[1.], [-1.]];
+    assert_abs_diff_eq!(
+        y.atan2(&array![[3f64.sqrt()]]),
+        array![[PI / 6.], [-PI / 6.]],
+        epsilon = 1e-15
+    );
+    assert_abs_diff_eq!(
+        array![[1., 1.], [-1., -1.]].atan2(&array![0., -1.]),
+        array![[PI / 2., 3. * PI / 4.], [-PI / 2., -3. * PI / 4.]],
+        epsilon = 1e-15
+    );
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn transcendental() {
+    use std::f64::consts::{E, PI};
+
+    let a = array![0., PI / 2., PI];
+    assert_abs_diff_eq!(a.sin(), array![0., 1., 0.], epsilon = 1e-15);
+    assert_abs_diff_eq!(a.cos(), array![1., 0., -1.], epsilon = 1e-15);
+    assert_abs_diff_eq!(array![0., PI / 4.].tan(), array![0., 1.], epsilon = 1e-15);
+    assert_eq!(array![0.].tanh(), array![0.]);
+    assert_abs_diff_eq!(array![0., 1., -1.].exp(), array![1., E, 1. / E], epsilon = 1e-15);
+    assert_abs_diff_eq!(array![1., E, E * E].ln(), array![0., 1., 2.], epsilon = 1e-15);
+    assert_eq!(array![1., 10., 1000.].log10(), array![0., 1., 3.]);
+    assert_eq!(array![[1., 8.], [0.5, 1024.]].log2(), array![[0., 3.], [-1., 10.]]);
+    assert_eq!(array![4., 0., 2.25].sqrt(), array![2., 0., 1.5]);
+    assert!(array![-1f64].sqrt()[0].is_nan());
+    assert_eq!(array![27f32, -8.].cbrt(), array![3., -2.]);
+
+    let s = array![0., 1., -1., 800., -800.].sigmoid();
+    assert_abs_diff_eq!(
+        s,
+        array![0.5, 1. / (1. + 1. / E), 1. / (1. + E), 1., 0.],
+        epsilon = 1e-15
+    );
+
+    let mut b = array![[1., 4.], [9., 16.]];
+    b.sqrt_inplace();
+    assert_eq!(b, array![[1., 2.], [3., 4.]]);
+    b.slice_mut(s![.., 0]).log2_inplace();
+    assert_eq!(b, array![[0., 2.], [3f64.log2(), 4.]]);
+    b.sigmoid_inplace();
+    assert_eq!(b[[0, 0]], 0.5);
+    let mut c = array![0f32];
+    c.exp_inplace();
+    c.ln_inplace();
+    assert_eq!(c, array![0.]);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn activations() {
+    let a = array![-3., -0.5, 0., 0.5, 2.];
+    assert_eq!(a.relu(), a.mapv(|x: f64| if x > 0. { x } else { 0. }));
+    assert_eq!(a.leaky_relu(0.01), a.mapv(|x| if x > 0. { x } else { 0.01 * x }));
+    assert_abs_diff_eq!(
+        a.elu(0.7),
+        a.mapv(|x| if x > 0. { x } else { 0.7 * (x.exp() - 1.) }),
+        epsilon = 1e-15
+    );
+    let gelu_ref = a.mapv(|x| {
+        0.5 * x * (1. + ((2. / std::f64::consts::PI).sqrt() * (x + 0.044715 * x.powi(3))).tanh())
+    });
+    assert_abs_diff_eq!(a.gelu(), gelu_ref, epsilon = 1e-15);
+    // the tanh approximation is close to the exact x * Φ(x)
+    assert_abs_diff_eq!(array![1.].gelu(), array![0.8413447460685429], epsilon = 1e-3);
+    assert!(array![f64::NAN].relu()[0].is_nan());
+
+    let mut b = a.clone();
+    b.relu_inplace();
+    assert_eq!(b, a.relu());
+    let mut b = a.clone();
+    b.leaky_relu_inplace(0.2);
+    assert_eq!(b, a.leaky_relu(0.2));
+    let mut b = a.clone();
+    b.elu_inplace(1.);
+    assert_eq!(b, a.elu(1.));
+    let mut b = a.clone();
+    b.gelu_inplace();
+    assert_eq!(b, a.gelu());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn logsumexp_axis() {
+    let a = array![[0.5, -1., 2.], [0., 3., 1.]];
+    let naive = a.mapv(f64::exp).sum_axis(Axis(1)).mapv(f64::ln);
+    assert_abs_diff_eq!(a.logsumexp_axis(Axis(1)), naive, epsilon = 1e-14);
+    let naive = a.mapv(f64::exp).sum_axis(Axis(0)).mapv(f64::ln);
+    assert_abs_diff_eq!(a.logsumexp_axis(Axis(0)), naive, epsilon = 1e-14);
+
+    // no overflow or underflow for large magnitudes
+    let b = array![[1000f64, 1000., 999.], [-1000., -1001., -1000.]];
+    let shifted = array![[0., 0., -1.], [0., -1., 0.]];
+    let expected = shifted.mapv(f64::exp).sum_axis(Axis(1)).mapv(f64::ln) + array![1000., -1000.];
+    let res = b.logsumexp_axis(Axis(1));
+    assert!(res.iter().all(|x| x.is_finite()));
+    assert_abs_diff_eq!(res, expected, epsilon = 1e-12);
+
+    let inf = f64::INFINITY;
+    let c = array![[-inf, -inf], [-inf, 1.], [inf, 1.], [f64::NAN, 1.], [f64::NAN, -inf]];
+    let res = c.logsumexp_axis(Axis(1));
+    assert_eq!(res.slice(s![..3]), array![-inf, 1., inf]);
+    assert!(res[3].is_nan() && res[4].is_nan());
+
+    let empty = Array2::<f64>::zeros((2, 0));
+    assert_eq!(empty.logsumexp_axis(Axis(1)), array![-inf, -inf]);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn cross_entropy_batch() {
+    let logits = array![[2., 1., 0.1], [0.5, 2.5, -1.], [-3., 0., 3.]];
+    let targets = array![0, 2, 2];
+    let expected = Array::from_shape_fn(3, |i| {
+        let row = logits.row(i);
+        let max = row.fold(f64::NEG_INFINITY, |m, &x| m.max(x));
+        let lse = max + row.mapv(|x| (x - max).exp()).sum().ln();
+        lse - row[targets[i]]
+    });
+    assert_abs_diff_eq!(cross_entropy(&logits, &targets), expected, epsilon = 1e-14);
+
+    // equal to -ln of the softmax probability
+    let probs = logits.mapv(f64::exp);
+    let p0 = probs[[0, 0]] / probs.row(0).sum();
+    assert_abs_diff_eq!(cross_entropy(&logits, &targets)[0], -p0.ln(), epsilon = 1e-14);
+
+    // large logits don't overflow
+    let loss = cross_entropy(&array![[1000., 0.], [-1000., 0.]], &array![0, 0]);
+    assert_abs_diff_eq!(loss, array![0., 1000.], epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn cross_entropy_target_out_of_range() {
+    cross_entropy(&array![[1., 2.], [3., 4.]], &array![0, 2]);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn cross_entropy_soft_targets() {
+    let logits = array![[2., 1., 0.1], [0.5, 2.5, -1.], [-3., 0., f64::NEG_INFINITY]];
+    let targets = array![0, 2, 1];
+    let one_hot = Array::from_shape_fn((3, 3), |(i, j)| if targets[i] == j { 1. } else { 0. });
+    assert_abs_diff_eq!(
+        cross_entropy_soft(&logits, &one_hot),
+        cross_entropy(&logits, &targets),
+        epsilon = 1e-14
+    );
+
+    // label smoothing
+    let smooth = one_hot.mapv(|t| 0.9 * t + 0.1 / 3.);
+    let loss = cross_entropy_soft(&logits.slice(s![..2, ..]), &smooth.slice(s![..2, ..]));
+    for i in 0..2 {
+        let probs = logits.row(i).mapv(f64::exp);
+        let probs = &probs / probs.sum();
+        let expected = -(&smooth.row(i) * &probs.mapv(f64::ln)).sum();
+        assert_abs_diff_eq!(loss[i], expected, epsilon = 1e-14);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn cross_entropy_soft_shape_mismatch() {
+    cross_entropy_soft(&array![[1., 2.], [3., 4.]], &array![[1., 0.]]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn pdist_euclidean() {
+    let points = array![[0., 0.], [3., 4.], [-1., 1.], [2., -2.]];
+    let d = points.pdist(Metric::Euclidean);
+    assert_eq!(d.dim(), (4, 4));
+    assert_eq!(d, d.t());
+    assert!(d.diag().iter().all(|&x| x == 0.));
+    assert_eq!(d[[0, 1]], 5.);
+    assert_eq!(d[[1, 2]], 5.);
+    assert_abs_diff_eq!(d[[2, 3]], 3f64.hypot(3.), epsilon = 1e-15);
+    assert_eq!(d, cdist(&points, &points, Metric::Euclidean));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn distance_metrics() {
+    let a = array![[1., 0., 2.], [0., 0., 0.]];
+    let b = array![[2., 3., 0.], [-1., 0., -2.]];
+    assert_eq!(cdist(&a, &b, Metric::Manhattan), array![[6., 6.], [5., 3.]]);
+    assert_eq!(cdist(&a, &b, Metric::Chebyshev), array![[3., 4.], [3., 2.]]);
+    let cos = cdist(&a, &b, Metric::Cosine);
+    assert_abs_diff_eq!(cos[[0, 0]], 1. - 2. / (5f64.sqrt() * 13f64.sqrt()), epsilon = 1e-15);
+    assert_abs_diff_eq!(cos[[0, 1]], 2., epsilon = 1e-15);
+    assert!(cos.row(1).iter().all(|x| x.is_nan()));
+    assert_eq!(cdist(&b, &a, Metric::Chebyshev), array![[3., 3.], [4., 2.]]);
+
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(cdist(&empty, &a, Metric::Euclidean).dim(), (0, 2));
+    assert_eq!(empty.pdist(Metric::Euclidean).dim(), (0, 0));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn knn_labeled_points() {
+    // two clusters, labeled 0 and 1
+    let points = array![[0., 0.], [10., 10.], [0.5, 0.2], [9., 9.5], [-0.3, 0.4], [10.5, 9.]];
+    let labels = array![0, 1, 0, 1, 0, 1];
+    let query = array![[0.1, 0.1], [9.8, 9.9], [4., 4.]];
+    let (indices, distances) = points.knn(&query, 3, Metric::Euclidean);
+    assert_eq!(indices.dim(), (3, 3));
+    assert!(indices.row(0).iter().all(|&i| labels[i] == 0));
+    assert!(indices.row(1).iter().all(|&i| labels[i] == 1));
+    assert_eq!(indices[[0, 0]], 0);
+    assert_eq!(indices[[1, 0]], 1);
+    let all = cdist(&query, &points, Metric::Euclidean);
+    for (i, row) in indices.outer_iter().enumerate() {
+        for (j, &index) in row.iter().enumerate() {
+            assert_eq!(distances[[i, j]], all[[i, index]]);
+        }
+        assert!(distances.row(i).windows(2).into_iter().all(|w| w[0] <= w[1]));
+    }
+
+    // ties are ordered by index
+    let (indices, _) = points.knn(&array![[5., 5.]], 6, Metric::Chebyshev);
+    assert_eq!(indices, array![[3, 2, 0, 1, 4, 5]]);
+    let (indices, distances) = points.knn(&query, 0, Metric::Euclidean);
+    assert_eq!((indices.dim(), distances.dim()), ((3, 0), (3, 0)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn knn_too_many_neighbors() {
+    array![[0., 0.], [1., 1.]].knn(&array![[0., 0.]], 3, Metric::Euclidean);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "approx"))]
+fn standard_scaler() {
+    let train = array![[1., -2., 5.], [2., 0., 5.], [3., 2., 5.], [6., 4., 5.]];
+    let mut scaler = StandardScaler::new();
+    assert_eq!(scaler.mean(), None);
+    let scaled = scaler.fit_transform(&train.view());
+    assert_abs_diff_eq!(scaler.mean().unwrap(), array![3., 1., 5.]);
+    assert_abs_diff_eq!(scaler.std().unwrap(), train.std_axis(Axis(0), 0.));
+    assert_abs_diff_eq!(scaled.mean_axis(Axis(0)).unwrap(), array![0., 0., 0.], epsilon = 1e-12);
+    assert_abs_diff_eq!(scaled.std_axis(Axis(0), 0.), array![1., 1., 0.], epsilon = 1e-12);
+
+    // the training statistics are applied to other data
+    let test = array![[3., 1., 7.], [-1., 10., 5.]];
+    let std = scaler.std().unwrap().to_owned();
+    let scaled = scaler.transform(&test.view());
+    assert_abs_diff_eq!(
+        scaled,
+        array![[0., 0., 2.], [-4. / std[0], 9. / std[1], 0.]],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(scaler.inverse_transform(&scaled.view()), test, epsilon = 1e-12);
+
+    // refitting replaces the parameters
+    scaler.fit(&test.view());
+    assert_abs_diff_eq!(scaler.mean().unwrap(), array![1., 5.5, 6.]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn standard_scaler_not_fitted() {
+    StandardScaler::new().transform(&array![[1., 2.]].view());
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn standard_scaler_column_mismatch() {
+    let mut scaler = StandardScaler::new();
+    scaler.fit(&array![[1., 2.], [3., 4.]].view());
+    scaler.transform(&array![[1., 2., 3.]].view());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn min_max_scaler() {
+    let train = array![[0., -1., 3.], [5., 1., 3.], [10., f64::NAN, 3.]];
+    let mut scaler = MinMaxScaler::new(-1., 1.);
+    assert_eq!(scaler.data_min(), None);
+    let scaled = scaler.fit_transform(&train.view());
+    assert_eq!(scaler.data_min().unwrap(), array![0., -1., 3.]);
+    assert_eq!(scaler.data_max().unwrap(), array![10., 1., 3.]);
+    assert_eq!(scaled.column(0), array![-1., 0., 1.]);
+    assert_eq!(scaled.slice(s![..2, 1]), array![-1., 1.]);
+    assert!(scaled[[2, 1]].is_nan());
+    assert_eq!(scaled.column(2), array![-1., -1., -1.]);
+
+    // test data outside of the fitted range extrapolates
+    let test = array![[-5., 3., 3.], [15., 0., 4.]];
+    let scaled = scaler.transform(&test.view());
+    assert_eq!(scaled, array![[-2., 3., -1.], [2., 0., -1.]]);
+    assert_eq!(
+        scaler.inverse_transform(&scaled.view()),
+        array![[-5., 3., 3.], [15., 0., 3.]]
+    );
+
+    let scaler = scaler.clip(true);
+    assert_eq!(scaler.transform(&test.view()), array![[-1., 1., -1.], [1., 0., -1.]]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn min_max_scaler_empty_range() {
+    MinMaxScaler::new(1., 1.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn min_max_scaler_not_fitted() {
+    MinMaxScaler::new(0., 1.).transform(&array![[1., 2.]].view());
+}
+
+#[test]
+fn binarize() {
+    let a = array![[-1.5, 0., 0.25], [2., f64::NAN, 0.]];
+    assert_eq!(a.binarize(0.), array![[0., 0., 1.], [1., 0., 0.]]);
+    assert_eq!(a.binarize(0.25), array![[0., 0., 0.], [1., 0., 0.]]);
+    assert_eq!(a.binarize(-f64::INFINITY), array![[1., 1., 1.], [1., 0., 1.]]);
+    assert_eq!(a.binarize(f64::NAN), Array2::zeros((2, 3)));
+    assert_eq!(array![3u8, 0, 7].binarize(3), array![0, 0, 1]);
+}
+
+#[test]
+fn polynomial_features() {
+    let x = array![[2., 3.], [0.5, -1.], [0., 0.]];
+    let (a, b) = (x.column(0), x.column(1));
+    let features = x.polynomial_features(2, true);
+    assert_eq!(features.dim(), (3, 6));
+    assert_eq!(features.column(0), Array1::<f64>::ones(3));
+    assert_eq!(features.column(1), a);
+    assert_eq!(features.column(2), b);
+    assert_eq!(features.column(3), &a * &a);
+    assert_eq!(features.column(4), &a * &b);
+    assert_eq!(features.column(5), &b * &b);
+    assert_eq!(x.polynomial_features(2, false), features.slice(s![.., 1..]));
+
+    // degree 3 of three features: 1 + 3 + 6 + 10 columns
+    let y = array![[1, 2, 3]];
+    assert_eq!(
+        y.polynomial_features(3, true).row(0),
+        aview1(&[1, 1, 2, 3, 1, 2, 3, 4, 6, 9, 1, 2, 3, 4, 6, 9, 8, 12, 18, 27])
+    );
+    assert_eq!(y.polynomial_features(1, false), y);
+    assert_eq!(y.polynomial_features(0, true), array![[1]]);
+    assert_eq!(y.polynomial_features(0, false).dim(), (1, 0));
+    assert_eq!(Array2::<i32>::zeros((0, 2)).polynomial_features(2, true).dim(), (0, 6));
+}
+
+#[test]
+fn confusion_matrix_counts() {
+    let y_true = array![0, 0, 1, 1, 1, 2, 2, 2, 2, 3];
+    let y_pred = array![0, 1, 1, 1, 0, 2, 2, 3, 0, 3];
+    let m = confusion_matrix(&y_true, &y_pred.view(), 5);
+    assert_eq!(
+        m,
+        array![
+            [1, 1, 0, 0, 0],
+            [1, 2, 0, 0, 0],
+            [1, 0, 2, 1, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0]
+        ]
+    );
+    assert_eq!(m.sum(), y_true.len());
+    assert_eq!(m.diag().sum(), 6);
+    assert_eq!(confusion_matrix(&array![], &array![], 2), Array2::zeros((2, 2)));
+}
+
+#[test]
+#[should_panic]
+fn confusion_matrix_label_out_of_range() {
+    confusion_matrix(&array![0, 1], &array![0, 2], 2);
+}
+
+#[test]
+#[should_panic]
+fn confusion_matrix_length_mismatch() {
+    confusion_matrix(&array![0, 1], &array![0], 2);
+}
+
+#[test]
+fn classification_metrics() {
+    let y_true = array![0, 0, 1, 1, 1, 2, 2, 2, 2, 3];
+    let y_pred = array![0, 1, 1, 1, 0, 2, 2, 3, 0, 3];
+    assert_eq!(accuracy(&y_true, &y_pred), 0.6);
+    assert!(accuracy(&Array1::<usize>::zeros(0), &array![]).is_nan());
+
+    // micro averages equal the accuracy
+    let confusion = confusion_matrix(&y_true, &y_pred, 5);
+    let correct = confusion.diag().sum() as f64;
+    assert_eq!(correct / confusion.sum() as f64, accuracy(&y_true, &y_pred));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn precision_recall_f1_per_class() {
+    let y_true = array![0, 0, 1, 1, 1, 2, 2, 2, 2, 3];
+    let y_pred = array![0, 1, 1, 1, 0, 2, 2, 3, 0, 3];
+    // class 4 never occurs and is never predicted
+    let confusion = confusion_matrix(&y_true, &y_pred, 5);
+    let (precision, recall, f1) = precision_recall_f1(&confusion);
+    assert_abs_diff_eq!(precision, array![1. / 3., 2. / 3., 1., 0.5, 0.], epsilon = 1e-12);
+    assert_abs_diff_eq!(recall, array![0.5, 2. / 3., 0.5, 1., 0.], epsilon = 1e-12);
+    assert_abs_diff_eq!(f1, array![0.4, 2. / 3., 2. / 3., 2. / 3., 0.], epsilon = 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn precision_recall_f1_not_square() {
+    precision_recall_f1(&Array2::<usize>::zeros((2, 3)));
+}
+
+#[test]
+fn regression_metrics() {
+    let y_true = array![[3., -0.5], [2., 7.]];
+    let y_pred = array![[2.5, 0.], [2., 8.]];
+    assert_abs_diff_eq!(mse(&y_true, &y_pred), (0.25 + 0.25 + 0. + 1.) / 4.);
+    assert_abs_diff_eq!(mae(&y_true, &y_pred), (0.5 + 0.5 + 0. + 1.) / 4.);
+    let mean = 11.5 / 4.;
+    let ss_tot = y_true.mapv(|y: f64| (y - mean).powi(2)).sum();
+    assert_abs_diff_eq!(r2_score(&y_true, &y_pred), 1. - 1.5 / ss_tot, epsilon = 1e-12);
+
+    // perfect predictions
+    assert_eq!(mse(&y_true, &y_true), 0.);
+    assert_eq!(mae(&y_true, &y_true.view()), 0.);
+    assert_eq!(r2_score(&y_true, &y_true), 1.);
+
+    // constant targets
+    let constant = array![2., 2., 2.];
+    assert_eq!(r2_score(&constant, &constant), 1.);
+    assert_eq!(r2_score(&constant, &array![2., 2., 3.]), 0.);
+
+    let empty = Array1::<f64>::zeros(0);
+    assert!(mse(&empty, &empty).is_nan());
+    assert!(r2_score(&empty, &empty).is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn regression_metrics_rmse() {
+    let y_true = array![[3., -0.5], [2., 7.]];
+    let y_pred = array![[2.5, 0.], [2., 8.]];
+    assert_abs_diff_eq!(rmse(&y_true, &y_pred), 0.375f64.sqrt());
+}
+
+#[test]
+#[should_panic]
+fn regression_metrics_shape_mismatch() {
+    mse(&array![[1., 2.]], &array![[1.], [2.]]);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn roc_curve_and_auc() {
+    // reference values as computed by scikit-learn's roc_curve(drop_intermediate=False)
+    let scores = array![0.9, 0.8, 0.7, 0.6, 0.55, 0.54, 0.53, 0.52, 0.51, 0.505];
+    let labels = array![true, true, false, true, true, true, false, false, true, false];
+    let (fpr, tpr, thresholds) = roc_curve(&scores, &labels);
+    let mut expected_thresholds = vec![f64::INFINITY];
+    expected_thresholds.extend(scores.iter());
+    assert_eq!(thresholds, Array1::from(expected_thresholds));
+    assert_abs_diff_eq!(
+        fpr,
+        array![0., 0., 0., 0.25, 0.25, 0.25, 0.25, 0.5, 0.75, 0.75, 1.]
+    );
+    assert_abs_diff_eq!(
+        tpr,
+        array![0., 1. / 6., 2. / 6., 2. / 6., 3. / 6., 4. / 6., 5. / 6., 5. / 6., 5. / 6., 1., 1.],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(auc(&fpr, &tpr), 0.75, epsilon = 1e-12);
+}
+
+#[test]
+fn roc_curve_ties() {
+    // tied scores share a point; the samples are shuffled
+    let scores = array![0.2, 0.5, 0.5];
+    let labels = array![false, true, false];
+    let (fpr, tpr, thresholds) = roc_curve(&scores, &labels);
+    assert_eq!(thresholds, array![f64::INFINITY, 0.5, 0.2]);
+    assert_eq!(fpr, array![0., 0.5, 1.]);
+    assert_eq!(tpr, array![0., 1., 1.]);
+    assert_eq!(auc(&fpr, &tpr), 0.75);
+
+    // a perfect classifier
+    let (fpr, tpr, _) = roc_curve(&array![0.1, 0.9, 0.8, 0.3], &array![false, true, true, false]);
+    assert_eq!(auc(&fpr, &tpr), 1.);
+
+    assert_eq!(auc(&array![1.], &array![1.]), 0.);
+}
+
+#[test]
+#[should_panic]
+fn roc_curve_nan_score() {
+    roc_curve(&array![0.1, f64::NAN], &array![false, true]);
+}
+
+#[test]
+fn cumsum_cumprod_axis() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k + 1) as i64);
+    for axis in 0..3 {
+        let cumsum = a.cumsum_axis(Axis(axis));
+        let cumprod = a.cumprod_axis(Axis(axis));
+        for i in 0..a.len_of(Axis(axis)) {
+            let upto = a.slice_axis(Axis(axis), ndarray::Slice::from(..=i));
+            assert_eq!(cumsum.index_axis(Axis(axis), i), upto.sum_axis(Axis(axis)));
+            assert_eq!(
+                cumprod.index_axis(Axis(axis), i),
+                upto.fold_axis(Axis(axis), 1, |&acc, &x| acc * x)
+            );
+        }
+    }
+
+    // non-standard layout and in place
+    let mut b = arr2(&[[1., 2.], [3., 4.], [5., 6.]]).reversed_axes();
+    assert_eq!(b.cumsum_axis(Axis(1)), arr2(&[[1., 4., 9.], [2., 6., 12.]]));
+    b.cumsum_axis_inplace(Axis(0));
+    assert_eq!(b, arr2(&[[1., 3., 5.], [3., 7., 11.]]));
+
+    // empty and length one axes
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(empty.cumsum_axis(Axis(0)).shape(), &[0, 3]);
+    assert_eq!(empty.cumprod_axis(Axis(1)).shape(), &[0, 3]);
+    let row = arr2(&[[2., 3., 4.]]);
+    assert_eq!(row.cumsum_axis(Axis(0)), row);
+    assert_eq!(row.cumprod_axis(Axis(0)), row);
+}
+
+#[test]
+fn diff_axis() {
+    let a = arr1(&[1., 4., 9., 16., 25.]);
+    assert_eq!(a.diff_axis(Axis(0), 0), a);
+    assert_eq!(a.diff_axis(Axis(0), 1), arr1(&[3., 5., 7., 9.]));
+    assert_eq!(a.diff_axis(Axis(0), 2), arr1(&[2., 2., 2.]));
+    assert_eq!(a.diff_axis(Axis(0), 3), arr1(&[0., 0.]));
+    assert_eq!(a.diff_axis(Axis(0), 4), arr1(&[0.]));
+    assert_eq!(a.diff_axis(Axis(0), 5).shape(), &[0]);
+    assert_eq!(a.diff_axis(Axis(0), 100).shape(), &[0]);
+
+    // against the differences of each lane
+    let b = Array::from_shape_fn((3, 4, 5), |(i, j, k)| {
+        ((i * 7 + j * j * 3 + k * k * k) % 17) as i32
+    });
+    for axis in 0..3 {
+        for n in 0..4 {
+            let d = b.diff_axis(Axis(axis), n);
+            let mut expected_shape = b.shape().to_vec();
+            expected_shape[axis] -= n;
+            assert_eq!(d.shape(), &expected_shape[..]);
+            for (lane, d_lane) in b.lanes(Axis(axis)).into_iter().zip(d.lanes(Axis(axis))) {
+                let mut v = lane.to_vec();
+                for _ in 0..n {
+                    v = v.windows(2).map(|w| w[1] - w[0]).collect();
+                }
+                assert_eq!(d_lane.to_vec(), v);
+            }
+        }
+    }
+
+    // non-standard layout and empty axes
+    let c = arr2(&[[1, 2], [4, 8], [9, 18]]);
+    assert_eq!(c.t().diff_axis(Axis(1), 1), arr2(&[[3, 5], [6, 10]]));
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.diff_axis(Axis(0), 2).shape(), &[0, 3]);
+    assert_eq!(empty.diff_axis(Axis(1), 1).shape(), &[0, 2]);
+}
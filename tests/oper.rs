@@ -8,7 +8,7 @@
 use ndarray::linalg::general_mat_mul;
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
-use ndarray::{Data, LinalgScalar};
+use ndarray::{Data, FoldWhile, LinalgScalar};
 use ndarray::{Ix, Ixs};
 use num_traits::Zero;
 
@@ -145,6 +145,60 @@ fn scalar_operations() {
     }
 }
 
+#[test]
+fn scalar_lhs_operations() {
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    assert_eq!(2.0 * &a, &a * 2.0);
+    assert_eq!(10. - &a, arr2(&[[9., 8.], [7., 6.]]));
+    assert_eq!(12. / &a, arr2(&[[12., 6.], [4., 3.]]));
+    assert_eq!(1. + a.clone(), &a + 1.);
+
+    let b = arr1(&[1, 2, 3]);
+    assert_eq!(2 * &b, arr1(&[2, 4, 6]));
+    assert_eq!(10 - &b, arr1(&[9, 8, 7]));
+    assert_eq!(10 - b.clone(), arr1(&[9, 8, 7]));
+    assert_eq!(6 / &b, arr1(&[6, 3, 2]));
+
+    let c = arr1(&[1usize, 2, 3]);
+    assert_eq!(3 * &c, arr1(&[3, 6, 9]));
+    assert_eq!(10 - &c, arr1(&[9, 8, 7]));
+    let d = arr1(&[1isize, -2, 3]);
+    assert_eq!(0 - &d, -&d);
+}
+
+#[test]
+fn neg_view() {
+    let a = arr2(&[[1., -2.], [3., -4.]]);
+    assert_eq!(-a.view(), arr2(&[[-1., 2.], [-3., 4.]]));
+    assert_eq!(-a.t(), arr2(&[[-1., -3.], [2., 4.]]));
+    assert_eq!(-a.slice(s![.., 1]), arr1(&[2., 4.]));
+    assert_eq!(-a.slice(s![..;-1, ..]), -&a.slice(s![..;-1, ..]));
+}
+
+#[test]
+fn sum_product_of_arrays() {
+    let arrays = vec![
+        arr2(&[[1, 2], [3, 4]]),
+        arr2(&[[10, 20], [30, 40]]),
+        arr2(&[[100, 200], [300, 400]]),
+    ];
+    let sum: Array2<i32> = arrays.iter().sum();
+    assert_eq!(sum, arr2(&[[111, 222], [333, 444]]));
+    let product: Array2<i32> = arrays.iter().product();
+    assert_eq!(product, arr2(&[[1000, 8000], [27000, 64000]]));
+    let sum: Array2<i32> = arrays.into_iter().sum();
+    assert_eq!(sum, arr2(&[[111, 222], [333, 444]]));
+
+    let mean = (0..4).map(|i| Array::from_elem((2, 2), i as f64)).sum::<Array2<f64>>() / 4.;
+    assert_eq!(mean, Array::from_elem((2, 2), 1.5));
+}
+
+#[test]
+#[should_panic]
+fn sum_of_no_arrays() {
+    let _: Array2<f64> = Vec::<Array2<f64>>::new().into_iter().sum();
+}
+
 fn reference_dot<'a, V1, V2>(a: V1, b: V2) -> f32
 where
     V1: AsArray<'a, f32>,
@@ -273,6 +327,60 @@ fn fold_and_sum() {
     }
 }
 
+#[test]
+fn fold_while_stops_early() {
+    let a = arr2(&[[1., 2., 3.], [-1., 5., 6.]]);
+    let mut visited = 0;
+    let sum = a.fold_while(0., |acc, &x| {
+        visited += 1;
+        if x < 0. {
+            FoldWhile::Done(acc)
+        } else {
+            FoldWhile::Continue(acc + x)
+        }
+    });
+    assert_eq!(sum, 6.);
+    assert_eq!(visited, 4);
+
+    // memory order for f-contiguous arrays
+    let mut visited = Vec::new();
+    let sum = a.t().fold_while(0., |acc, &x| {
+        visited.push(x);
+        if x < 0. {
+            FoldWhile::Done(acc)
+        } else {
+            FoldWhile::Continue(acc + x)
+        }
+    });
+    assert_eq!(sum, 6.);
+    assert_eq!(visited, vec![1., 2., 3., -1.]);
+
+    // non-contiguous arrays are visited completely without a stop
+    let a = Array::linspace(0., 127., 128).into_shape((8, 16)).unwrap();
+    let a1 = a.slice(s![..;2, ..;3]);
+    let sum = a1.fold_while(0., |acc, &x| FoldWhile::Continue(acc + x));
+    assert_abs_diff_eq!(sum, a1.sum(), epsilon = 1e-5);
+}
+
+#[test]
+fn try_fold_stops_at_error() {
+    let a = arr2(&[[1, 2, 3], [-1, 5, 6]]);
+    let mut visited = 0;
+    let res = a.try_fold(0, |acc, &x| {
+        visited += 1;
+        if x < 0 {
+            Err(x)
+        } else {
+            Ok(acc + x)
+        }
+    });
+    assert_eq!(res, Err(-1));
+    assert_eq!(visited, 4);
+
+    let res = a.slice(s![.., 1..]).try_fold(0, |acc, &x| if x < 0 { Err(x) } else { Ok(acc + x) });
+    assert_eq!(res, Ok(16));
+}
+
 #[test]
 fn product() {
     let a = Array::linspace(0.5, 2., 128).into_shape((8, 16)).unwrap();
@@ -427,6 +535,110 @@ fn mat_mul_order() {
     assert_eq!(ff.strides()[0], 1);
 }
 
+// Check that the matrix wrapper multiplies matrices while arrays multiply elementwise
+#[test]
+fn mat_wrapper_mul() {
+    let a = range_i32(3, 4);
+    let b = range_i32(4, 2);
+    assert_eq!(a.mat() * b.mat(), reference_mat_mul(&a, &b));
+    assert_eq!(a.mat() * b.mat(), a.dot(&b));
+
+    let c = range_i32(3, 3);
+    let d = c.t();
+    assert_eq!(c.mat() * d.mat(), reference_mat_mul(&c, &d));
+    assert_eq!(&c * &d, arr2(&[[0, 3, 12], [3, 16, 35], [12, 35, 64]]));
+    assert_ne!(&c * &d, c.mat() * d.mat());
+    assert_eq!(c.mat().view(), c);
+}
+
+#[test]
+#[should_panic]
+fn mat_wrapper_mul_shape_mismatch() {
+    let a = range_mat(3, 4);
+    let _ = a.mat() * a.mat();
+}
+
+#[test]
+fn kron_product() {
+    use ndarray::linalg::kron;
+
+    let a = range_mat(2, 3);
+    let b = range_mat(4, 2);
+    let k = kron(&a, &b);
+    assert_eq!(k.dim(), (8, 6));
+    for ((r, c), &x) in k.indexed_iter() {
+        assert_eq!(x, a[(r / 4, c / 2)] * b[(r % 4, c % 2)]);
+    }
+    assert_eq!(k.slice(s![4.., 2..4]), &b * a[(1, 1)]);
+
+    // non-contiguous operands
+    assert_eq!(kron(&a.t(), &b.t()), k.t());
+
+    let i = Array2::<f32>::eye(2);
+    assert_eq!(kron(&i, &b), ndarray::block_diag(&[b.view(), b.view()]));
+    assert_eq!(kron(&arr2(&[[2.]]), &b), &b * 2.);
+    assert_eq!(kron(&Array2::<f32>::zeros((0, 3)), &b).dim(), (0, 6));
+    assert_eq!(kron(&a, &Array2::<f32>::zeros((4, 0))).dim(), (8, 0));
+}
+
+#[test]
+fn outer_product() {
+    use ndarray::linalg::{outer, outer_with};
+
+    let a = Array::linspace(1., 4., 4);
+    let b = Array::linspace(-1., 1., 3);
+    let o = outer(&a, &b);
+    assert_eq!(o.dim(), (4, 3));
+    assert_eq!(o, a.view().insert_axis(Axis(1)).to_owned() * b.view().insert_axis(Axis(0)));
+    assert_eq!(o, a.view().into_shape((4, 1)).unwrap().dot(&b.view().into_shape((1, 3)).unwrap()));
+    assert_eq!(outer(&b, &a), o.t());
+
+    // strided inputs and mixed element types
+    let c = outer(&a.slice(s![..;2]), &b.slice(s![..;-1]));
+    assert_eq!(c, arr2(&[[1., 0., -1.], [3., 0., -3.]]));
+    let words = outer_with(&arr1(&["a", "b"]), &arr1(&[1, 2]), |s, &n| s.repeat(n));
+    assert_eq!(words, arr2(&[["a".to_string(), "aa".to_string()], ["b".to_string(), "bb".to_string()]]));
+
+    assert_eq!(outer(&a, &Array1::<f32>::zeros(0)).dim(), (4, 0));
+}
+
+#[test]
+fn batched_mat_mul() {
+    let a = Array::from_shape_fn((4, 3, 5), |(i, j, k)| (i * 15 + j * 5 + k) as f64);
+    let b = Array::from_shape_fn((4, 5, 2), |(i, j, k)| (i + j) as f64 - k as f64);
+    let c = a.batched_dot(&b);
+    assert_eq!(c.dim(), (4, 3, 2));
+    for i in 0..4 {
+        assert_eq!(c.index_axis(Axis(0), i), a.index_axis(Axis(0), i).dot(&b.index_axis(Axis(0), i)));
+    }
+
+    // non-contiguous inputs
+    let at = a.view().permuted_axes([0, 2, 1]);
+    let bt = b.view().permuted_axes([0, 2, 1]);
+    assert_eq!(bt.batched_dot(&at), c.view().permuted_axes([0, 2, 1]));
+    let c2 = a.slice(s![..;-2, .., ..]).batched_dot(&b.slice(s![..;-2, .., ..]));
+    assert_eq!(c2, c.slice(s![..;-2, .., ..]));
+
+    let empty = Array3::<f64>::zeros((0, 3, 5));
+    assert_eq!(empty.batched_dot(&Array3::zeros((0, 5, 2))).dim(), (0, 3, 2));
+}
+
+#[test]
+#[should_panic(expected = "not compatible for batched matrix multiplication")]
+fn batched_mat_mul_batch_mismatch() {
+    let a = Array3::<f32>::zeros((2, 3, 4));
+    let b = Array3::<f32>::zeros((3, 4, 3));
+    a.batched_dot(&b);
+}
+
+#[test]
+#[should_panic(expected = "not compatible for batched matrix multiplication")]
+fn batched_mat_mul_inner_mismatch() {
+    let a = Array3::<f32>::zeros((2, 3, 4));
+    let b = Array3::<f32>::zeros((2, 3, 4));
+    a.batched_dot(&b);
+}
+
 // test matrix multiplication shape mismatch
 #[test]
 #[should_panic]
@@ -520,6 +732,20 @@ fn scaled_add() {
     assert_eq!(c, d);
 }
 
+#[test]
+fn scaled_add_broadcast() {
+    let mut a = Array::from_elem((3, 4), 1);
+    a.scaled_add(3, &arr1(&[0, 1, 2, 3]));
+    assert_eq!(a, arr2(&[[1, 4, 7, 10], [1, 4, 7, 10], [1, 4, 7, 10]]));
+
+    let mut b = Array::from_elem((3, 2), 0.5);
+    b.scaled_add(-2., &arr2(&[[1.], [2.], [3.]]));
+    assert_eq!(b, arr2(&[[-1.5, -1.5], [-3.5, -3.5], [-5.5, -5.5]]));
+
+    b.scaled_add(2., &aview0(&0.25));
+    assert_eq!(b, arr2(&[[-1., -1.], [-3., -3.], [-5., -5.]]));
+}
+
 #[cfg(feature = "approx")]
 #[test]
 fn scaled_add_2() {
@@ -693,6 +919,55 @@ fn gen_mat_mul_i32() {
     }
 }
 
+#[cfg(feature = "approx")]
+#[test]
+fn gen_mat_mul_uninit() {
+    use ndarray::linalg::general_mat_mul_uninit;
+
+    let alpha = -2.3;
+    let sizes = vec![(4, 4, 4), (17, 15, 16), (4, 17, 3), (19, 18, 2), (67, 63, 62)];
+    for &s1 in &[1, -2] {
+        for &s2 in &[1, 2, -1] {
+            for &(m, k, n) in &sizes {
+                let a = range_mat64(m, k);
+                let b = range_mat64(k, n);
+                let a = a.slice(s![..;s1, ..;s2]);
+                let b = b.slice(s![..;s2, ..;s2]);
+                let answer = alpha * reference_mat_mul(&a, &b);
+
+                let (m, n) = (a.nrows(), b.ncols());
+                for &f_order in &[false, true] {
+                    let mut c = Array2::uninit((m, n).set_f(f_order));
+                    let cv = general_mat_mul_uninit(alpha, &a, &b, c.view_mut());
+                    approx::assert_relative_eq!(cv, answer, epsilon = 1e-12, max_relative = 1e-7);
+                    let c = unsafe { c.assume_init() };
+                    approx::assert_relative_eq!(c, answer, epsilon = 1e-12, max_relative = 1e-7);
+                }
+
+                // into a strided part of a larger output
+                let mut c = Array2::uninit((2 * m, n));
+                general_mat_mul_uninit(alpha, &a, &b, c.slice_mut(s![..;-2, ..]));
+                let c = unsafe { c.slice(s![..;-2, ..]).assume_init() };
+                approx::assert_relative_eq!(c, answer, epsilon = 1e-12, max_relative = 1e-7);
+            }
+        }
+    }
+
+    // the general path for other element types
+    let (a, b) = (range_i32(5, 7), range_i32(7, 3));
+    let mut c = Array2::uninit((5, 3));
+    general_mat_mul_uninit(2, &a, &b, c.view_mut());
+    assert_eq!(unsafe { c.assume_init() }, 2 * reference_mat_mul(&a, &b));
+}
+
+#[test]
+#[should_panic(expected = "are not compatible for matrix multiplication")]
+fn gen_mat_mul_uninit_shape_mismatch() {
+    let a = range_mat(3, 4);
+    let mut c = Array2::uninit((3, 4));
+    ndarray::linalg::general_mat_mul_uninit(1., &a, &a.t(), c.view_mut());
+}
+
 #[cfg(feature = "approx")]
 #[test]
 fn gen_mat_vec_mul() {
@@ -0,0 +1,95 @@
+use ndarray::prelude::*;
+use ndarray::PadMode;
+
+#[test]
+fn pad_1d_modes() {
+    let a = arr1(&[1, 2, 3]);
+    assert_eq!(a.pad(&[(0, 0)], PadMode::Edge), a);
+    assert_eq!(a.pad(&[(1, 3)], PadMode::Constant(9)), arr1(&[9, 1, 2, 3, 9, 9, 9]));
+    assert_eq!(a.pad(&[(0, 2)], PadMode::Edge), arr1(&[1, 2, 3, 3, 3]));
+
+    // padding wider than the array repeats the pattern
+    assert_eq!(
+        a.pad(&[(5, 5)], PadMode::Reflect),
+        arr1(&[2, 1, 2, 3, 2, 1, 2, 3, 2, 1, 2, 3, 2])
+    );
+    assert_eq!(
+        a.pad(&[(5, 5)], PadMode::Wrap),
+        arr1(&[2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2])
+    );
+    assert_eq!(arr1(&[7]).pad(&[(2, 1)], PadMode::Reflect), arr1(&[7, 7, 7, 7]));
+
+    // non-Copy elements and a reversed view
+    let s = arr1(&["a", "b", "c"]).mapv(String::from);
+    let padded = s.slice(s![..;-1]).pad(&[(1, 1)], PadMode::Wrap);
+    assert_eq!(padded, arr1(&["a", "c", "b", "a", "c"]).mapv(String::from));
+}
+
+#[test]
+fn pad_2d_corners() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(
+        a.pad(&[(1, 1), (2, 0)], PadMode::Constant(0)),
+        arr2(&[
+            [0, 0, 0, 0, 0],
+            [0, 0, 1, 2, 3],
+            [0, 0, 4, 5, 6],
+            [0, 0, 0, 0, 0]
+        ])
+    );
+    assert_eq!(
+        a.pad(&[(1, 1), (1, 1)], PadMode::Edge),
+        arr2(&[
+            [1, 1, 2, 3, 3],
+            [1, 1, 2, 3, 3],
+            [4, 4, 5, 6, 6],
+            [4, 4, 5, 6, 6]
+        ])
+    );
+    // corners are reflected along both axes, as with NumPy
+    assert_eq!(
+        a.pad(&[(1, 1), (2, 1)], PadMode::Reflect),
+        arr2(&[
+            [6, 5, 4, 5, 6, 5],
+            [3, 2, 1, 2, 3, 2],
+            [6, 5, 4, 5, 6, 5],
+            [3, 2, 1, 2, 3, 2]
+        ])
+    );
+    assert_eq!(
+        a.pad(&[(1, 0), (1, 2)], PadMode::Wrap),
+        arr2(&[[6, 4, 5, 6, 4, 5], [3, 1, 2, 3, 1, 2], [6, 4, 5, 6, 4, 5]])
+    );
+
+    // padding is applied axis by axis
+    for &mode in &[PadMode::Constant(-1), PadMode::Edge, PadMode::Reflect, PadMode::Wrap] {
+        let both = a.pad(&[(2, 3), (1, 2)], mode);
+        let one_by_one = a.pad(&[(2, 3), (0, 0)], mode).pad(&[(0, 0), (1, 2)], mode);
+        assert_eq!(both, one_by_one);
+    }
+}
+
+#[test]
+fn pad_empty() {
+    let a = Array2::<i32>::zeros((0, 2));
+    assert_eq!(a.pad(&[(1, 1), (0, 1)], PadMode::Constant(5)), Array2::from_elem((2, 3), 5));
+    assert_eq!(a.pad(&[(0, 0), (1, 1)], PadMode::Edge).shape(), &[0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn pad_empty_axis_reflect() {
+    Array2::<i32>::zeros((0, 2)).pad(&[(1, 0), (0, 0)], PadMode::Reflect);
+}
+
+#[test]
+#[should_panic]
+fn pad_wrong_number_of_axes() {
+    arr2(&[[1, 2]]).pad(&[(1, 1)], PadMode::Edge);
+}
+
+#[test]
+#[should_panic(expected = "pad width overflows usize")]
+fn pad_width_overflow() {
+    arr1(&[1, 2]).pad(&[(usize::MAX, 1)], PadMode::Constant(0));
+}
@@ -86,3 +86,68 @@ fn test_axis_chunks_iter_mut() {
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_exact_chunks_mut() {
+    let mut a = Array::from_shape_fn((7, 10), |(i, j)| (i / 2) * 10 + j / 3);
+    a.par_exact_chunks_mut((2, 3)).for_each(|mut chunk| {
+        assert_eq!(chunk.dim(), (2, 3));
+        let first = chunk[[0, 0]];
+        chunk.fill(first + 100);
+    });
+    let expected = Array::from_shape_fn((7, 10), |(i, j)| {
+        let tile = (i / 2) * 10 + j / 3;
+        if i < 6 && j < 9 {
+            tile + 100
+        } else {
+            tile
+        }
+    });
+    assert_eq!(a, expected);
+    assert_eq!(a.par_exact_chunks_mut((2, 3)).count(), 9);
+}
+
+#[test]
+fn test_par_mapv() {
+    let a = Array::linspace(0., 63., M).into_shape((M / 16, 16)).unwrap();
+    let f = |x: f64| (0..100).fold(x, |acc, i| (acc + i as f64).sqrt());
+    let serial = a.mapv(f);
+    let parallel = a.par_mapv(f);
+    assert_eq!(parallel, serial);
+
+    let at = a.t();
+    let parallel = at.par_mapv(f);
+    assert!(parallel.is_standard_layout());
+    assert_eq!(parallel, serial.t());
+}
+
+#[test]
+fn test_par_move_into() {
+    use std::sync::Arc;
+
+    // large enough to be moved in parallel, with unreachable elements
+    let elem = Arc::new(());
+    let mut a = Array::from_shape_fn((400, 300), |(i, j)| (i * 300 + j, elem.clone()));
+    a.slice_collapse(s![..;-1, 50..]);
+    let expected = a.map(|&(x, _)| x);
+    let mut b = Array::uninit((400, 250).f());
+    a.par_move_into(&mut b);
+    let b = unsafe { b.assume_init() };
+    assert_eq!(b.map(|&(x, _)| x), expected);
+    assert_eq!(Arc::strong_count(&elem), 400 * 250 + 1);
+    drop(b);
+    assert_eq!(Arc::strong_count(&elem), 1);
+
+    // small arrays are moved serially
+    let c = array![[1, 2], [3, 4]];
+    let mut d = Array::uninit((2, 2));
+    c.par_move_into(&mut d);
+    assert_eq!(unsafe { d.assume_init() }, array![[1, 2], [3, 4]]);
+}
+
+#[test]
+#[should_panic]
+fn test_par_move_into_wrong_shape() {
+    let mut b = Array::uninit((200, 300));
+    Array::<i32, _>::zeros((300, 200)).par_move_into(&mut b);
+}
@@ -140,3 +140,26 @@ fn test_zip_assign_into() {
 
     assert_abs_diff_eq!(a, &b + &c, epsilon = 1e-6);
 }
+
+#[test]
+fn test_zip_par_fold() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * N + j) % 97) as i64);
+    let b = Array::from_shape_fn((M, N).f(), |(i, j)| ((i + 3 * j) % 13) as i64 - 6);
+
+    let dot = Zip::from(&a).and(&b).par_fold(|| 0, |acc, &x, &y| acc + x * y, |x, y| x + y);
+    assert_eq!(dot, Zip::from(&a).and(&b).fold(0, |acc, &x, &y| acc + x * y));
+
+    let max = Zip::from(&a).and(&b).par_fold(
+        || i64::MIN,
+        |acc, &x, &y| acc.max(x * y),
+        |x, y| x.max(y),
+    );
+    assert_eq!(max, 96 * 6);
+
+    // count the elements, to check that each is folded exactly once
+    let count = Zip::indexed(&a).par_fold(|| 0, |acc, _, _| acc + 1, |x, y| x + y);
+    assert_eq!(count, M * N);
+
+    let empty = Array2::<i64>::zeros((0, N));
+    assert_eq!(Zip::from(&empty).par_fold(|| 0, |acc, &x| acc + x, |x, y| x + y), 0);
+}
@@ -0,0 +1,199 @@
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, ShapeError};
+
+#[test]
+fn prepend_row() {
+    let mut a = Array::zeros((0, 4));
+    a.try_prepend_row(aview1(&[0., 1., 2., 3.])).unwrap();
+    a.try_prepend_row(aview1(&[4., 5., 6., 7.])).unwrap();
+    assert_eq!(a.shape(), &[2, 4]);
+    assert_eq!(a, array![[4., 5., 6., 7.], [0., 1., 2., 3.]]);
+    assert!(a.is_standard_layout());
+
+    assert_eq!(
+        a.try_prepend_row(aview1(&[1.])),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+    );
+    assert_eq!(a, array![[4., 5., 6., 7.], [0., 1., 2., 3.]]);
+
+    // prepending a column changes the layout to column major
+    a.try_prepend_column(aview1(&[1., 2.])).unwrap();
+    assert_eq!(a, array![[1., 4., 5., 6., 7.], [2., 0., 1., 2., 3.]]);
+    assert!(a.t().is_standard_layout());
+}
+
+#[test]
+fn prepend_row_sliced() {
+    // the vector holds elements that are no longer part of the array
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (10 * i + j) as i32);
+    a.slice_collapse(s![1..3, ..]);
+    a.try_prepend_row(aview1(&[-1, -2, -3])).unwrap();
+    assert_eq!(a, array![[-1, -2, -3], [10, 11, 12], [20, 21, 22]]);
+
+    let mut b = Array::from_shape_fn((4, 3), |(i, j)| (10 * i + j) as i32);
+    b.slice_collapse(s![..;-1, ..]);
+    b.try_prepend_row(aview1(&[-1, -2, -3])).unwrap();
+    assert_eq!(b, array![[-1, -2, -3], [30, 31, 32], [20, 21, 22], [10, 11, 12], [0, 1, 2]]);
+    assert!(b.is_standard_layout());
+}
+
+#[test]
+fn prepend_column() {
+    let mut a = Array::zeros((3, 0));
+    a.try_prepend_column(aview1(&[0., 1., 2.])).unwrap();
+    a.try_prepend_column(aview1(&[3., 4., 5.])).unwrap();
+    assert_eq!(a, array![[3., 0.], [4., 1.], [5., 2.]]);
+    assert!(a.t().is_standard_layout());
+
+    let mut b = Array::from_shape_vec((2, 2).f(), vec![1, 2, 3, 4]).unwrap();
+    b.try_prepend_column(aview1(&[7, 8])).unwrap();
+    assert_eq!(b, array![[7, 1, 3], [8, 2, 4]]);
+
+    assert_eq!(
+        b.try_prepend_column(aview1(&[1, 2, 3])),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+    );
+    b.try_prepend_row(aview1(&[5, 6, 9])).unwrap();
+    assert_eq!(b, array![[5, 6, 9], [7, 1, 3], [8, 2, 4]]);
+    assert!(b.is_standard_layout());
+}
+
+#[test]
+fn prepend_to_empty_and_single() {
+    // a single row or column is in both layouts
+    let mut a = array![[1, 2, 3]];
+    a.try_prepend_column(aview1(&[0])).unwrap();
+    assert_eq!(a, array![[0, 1, 2, 3]]);
+    a.try_prepend_row(aview1(&[4, 5, 6, 7])).unwrap();
+    assert_eq!(a, array![[4, 5, 6, 7], [0, 1, 2, 3]]);
+
+    let mut b = Array2::<String>::default((0, 0));
+    b.try_prepend_row(aview1(&[])).unwrap();
+    assert_eq!(b.shape(), &[1, 0]);
+    let mut c = Array2::<String>::default((0, 2));
+    c.try_prepend_row(aview1(&["a".to_string(), "b".to_string()])).unwrap();
+    c.try_prepend_row(aview1(&["c".to_string(), "d".to_string()])).unwrap();
+    assert_eq!(c, array![["c", "d"], ["a", "b"]].mapv(String::from));
+}
+
+#[test]
+fn prepend_array_3d() {
+    let mut a = Array3::<i32>::zeros((0, 2, 3));
+    for k in 0..3 {
+        let block = Array::from_shape_fn((1, 2, 3), |(_, i, j)| 100 * k + 10 * i as i32 + j as i32);
+        a.try_prepend_array(Axis(0), block.view()).unwrap();
+    }
+    assert_eq!(a.shape(), &[3, 2, 3]);
+    assert!(a.is_standard_layout());
+    for (k, sub) in a.outer_iter().enumerate() {
+        assert_eq!(sub[[1, 2]], 100 * (2 - k as i32) + 12);
+    }
+
+    // a block of several entries along the axis, given with negative strides
+    let block = Array::from_shape_fn((2, 2, 3), |(k, i, j)| -((100 * k + 10 * i + j) as i32));
+    a.try_prepend_array(Axis(0), block.slice(s![..;-1, ..;-1, ..])).unwrap();
+    assert_eq!(a.shape(), &[5, 2, 3]);
+    assert_eq!(a.slice(s![..2, .., ..]), block.slice(s![..;-1, ..;-1, ..]));
+    assert_eq!(a[[4, 0, 0]], 0);
+
+    assert_eq!(
+        a.try_prepend_array(Axis(0), Array3::zeros((1, 3, 2)).view()),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+    );
+    assert_eq!(
+        a.try_prepend_array(Axis(3), block.view()),
+        Err(ShapeError::from_kind(ErrorKind::OutOfBounds))
+    );
+}
+
+#[test]
+fn prepend_array_permuted_layout() {
+    // axis 1 is outermost in memory
+    let mut a = Array::from_shape_fn((2, 3, 2), |(i, j, k)| (100 * i + 10 * j + k) as i32);
+    a = a.permuted_axes([1, 0, 2]);
+    let expected_tail = a.clone();
+    let block = Array::from_shape_fn((3, 1, 2), |(j, _, k)| -((10 * j + k) as i32));
+    a.try_prepend_array(Axis(1), block.view()).unwrap();
+    assert_eq!(a.shape(), &[3, 3, 2]);
+    assert_eq!(a.slice(s![.., ..1, ..]), block);
+    assert_eq!(a.slice(s![.., 1.., ..]), expected_tail);
+    assert_eq!(a.strides(), &[2, 6, 1]);
+
+    // axis 0 is not outermost, so the array is laid out anew with axis 0 outermost
+    let expected = a.clone();
+    a.try_prepend_array(Axis(0), Array3::zeros((1, 3, 2)).view()).unwrap();
+    assert_eq!(a.shape(), &[4, 3, 2]);
+    assert_eq!(a.slice(s![1.., .., ..]), expected);
+    assert_eq!(a.slice(s![..1, .., ..]), Array3::zeros((1, 3, 2)));
+    assert!(a.is_standard_layout());
+}
+
+#[test]
+fn prepend_array_inverted_axis() {
+    let mut a = Array::from_shape_fn((3, 2), |(i, j)| (10 * i + j) as i32);
+    a.invert_axis(Axis(0));
+    assert_eq!(a.strides(), &[-2, 1]);
+    a.try_prepend_array(Axis(0), aview2(&[[-1, -2]])).unwrap();
+    assert_eq!(a, array![[-1, -2], [20, 21], [10, 11], [0, 1]]);
+    assert!(a.is_standard_layout());
+
+    // sliced and inverted along the other axis, with elements that need dropping
+    let mut b = Array::from_shape_fn((3, 4), |(i, j)| (10 * i + j).to_string());
+    b.slice_collapse(s![.., ..;-2]);
+    let block = array![["a", "b"]].mapv(String::from);
+    b.try_prepend_array(Axis(0), block.view()).unwrap();
+    assert_eq!(b, array![["a", "b"], ["3", "1"], ["13", "11"], ["23", "21"]].mapv(String::from));
+}
+
+#[test]
+fn prepend_array_axis_len_one() {
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (10 * i + j) as i32);
+    a.slice_collapse(s![2..3, ..]);
+    assert_eq!(a.shape(), &[1, 3]);
+    a.try_prepend_array(Axis(0), aview2(&[[1, 2, 3]])).unwrap();
+    assert_eq!(a, array![[1, 2, 3], [20, 21, 22]]);
+
+    // a single column of an F-order array can grow along axis 1
+    let mut b = Array::from_shape_vec((3, 1).f(), vec![1, 2, 3]).unwrap();
+    b.try_prepend_array(Axis(1), aview2(&[[7, 8], [9, 10], [11, 12]])).unwrap();
+    assert_eq!(b, array![[7, 8, 1], [9, 10, 2], [11, 12, 3]]);
+
+    let mut c = Array1::from(vec![1, 2]);
+    c.try_prepend_array(Axis(0), aview1(&[0])).unwrap();
+    assert_eq!(c, array![0, 1, 2]);
+}
+
+#[test]
+fn prepend_array_panic_in_clone() {
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct Noisy(Rc<Cell<usize>>, bool);
+    impl Clone for Noisy {
+        fn clone(&self) -> Self {
+            if self.1 {
+                panic!("clone");
+            }
+            Noisy(self.0.clone(), self.1)
+        }
+    }
+    impl Drop for Noisy {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let mut a = Array::from_shape_fn((2, 2), |_| Noisy(drops.clone(), false));
+    let block = Array::from_shape_fn((1, 2), |(_, j)| Noisy(drops.clone(), j == 1));
+    let res = catch_unwind(AssertUnwindSafe(|| a.try_prepend_array(Axis(0), block.view())));
+    assert!(res.is_err());
+    assert_eq!(a.shape(), &[2, 2]);
+    // only the one successful clone was dropped
+    assert_eq!(drops.get(), 1);
+    drop(a);
+    drop(block);
+    assert_eq!(drops.get(), 7);
+}
@@ -0,0 +1,133 @@
+use ndarray::prelude::*;
+
+#[test]
+fn sort_axis_rows_and_columns() {
+    let a = arr2(&[[3, 1, 2], [9, 7, 8], [6, 5, 4]]);
+
+    let mut rows = a.clone();
+    rows.sort_axis(Axis(1));
+    assert_eq!(rows, arr2(&[[1, 2, 3], [7, 8, 9], [4, 5, 6]]));
+
+    let mut cols = a.clone();
+    cols.sort_axis(Axis(0));
+    assert_eq!(cols, arr2(&[[3, 1, 2], [6, 5, 4], [9, 7, 8]]));
+
+    let mut cols = a.clone();
+    cols.sort_axis_unstable(Axis(0));
+    assert_eq!(cols, arr2(&[[3, 1, 2], [6, 5, 4], [9, 7, 8]]));
+
+    // non-contiguous lanes of a strided view
+    let mut b = Array::from_shape_fn((4, 6), |(i, j)| ((i * 7 + j * 5) % 11) as i32);
+    let expected = {
+        let mut v = b.slice(s![.., ..;-2]).to_owned();
+        for mut row in v.rows_mut() {
+            let mut r = row.to_vec();
+            r.sort();
+            row.assign(&Array::from(r));
+        }
+        v
+    };
+    b.slice_mut(s![.., ..;-2]).sort_axis(Axis(1));
+    assert_eq!(b.slice(s![.., ..;-2]), expected);
+}
+
+#[test]
+fn sort_axis_3d() {
+    let a = Array::from_shape_fn((3, 5, 4), |(i, j, k)| ((i * 13 + j * 7 + k * 3) % 10) as i32);
+    for axis in 0..3 {
+        let mut sorted = a.clone();
+        sorted.sort_axis(Axis(axis));
+        for (lane, sorted_lane) in a.lanes(Axis(axis)).into_iter().zip(sorted.lanes(Axis(axis))) {
+            let mut v = lane.to_vec();
+            v.sort();
+            assert_eq!(sorted_lane.to_vec(), v);
+        }
+    }
+}
+
+#[test]
+fn sort_axis_floats() {
+    let mut a = arr2(&[[0.5, -1., 2.], [3., 1., -2.]]);
+    a.sort_axis_by(Axis(0), |x, y| y.partial_cmp(x).unwrap());
+    assert_eq!(a, arr2(&[[3., 1., 2.], [0.5, -1., -2.]]));
+    a.sort_axis_unstable_by(Axis(1), |x, y| x.partial_cmp(y).unwrap());
+    assert_eq!(a, arr2(&[[1., 2., 3.], [-2., -1., 0.5]]));
+}
+
+#[test]
+fn sort_axis_stable() {
+    // Sort by key only; the payload records the original order
+    let mut a = Array::from_shape_fn((5, 2), |(i, j)| ((4 - i) / 2 + 10 * j, i));
+    a.sort_axis_by_key(Axis(0), |&(key, _)| key);
+    assert_eq!(
+        a.column(0),
+        arr1(&[(0, 3), (0, 4), (1, 1), (1, 2), (2, 0)])
+    );
+    assert_eq!(
+        a.column(1),
+        arr1(&[(10, 3), (10, 4), (11, 1), (11, 2), (12, 0)])
+    );
+}
+
+#[test]
+fn sort_axis_non_clone() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct NoClone(i32);
+    let mut a = Array::from_shape_fn((3, 3), |(i, j)| NoClone(((i + 2 * j) % 3) as i32));
+    a.sort_axis(Axis(0));
+    for col in a.columns() {
+        assert_eq!(col.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
+
+#[test]
+fn is_sorted_axis() {
+    let a = arr2(&[[1, 2, 3], [1, 1, 1], [3, 2, 1], [0, 5, 4]]);
+    assert_eq!(a.is_sorted_axis(Axis(1)), arr1(&[true, true, false, false]));
+    assert_eq!(a.is_sorted_axis(Axis(0)), arr1(&[false, false, false]));
+    assert_eq!(a.slice(s![..2, ..]).is_sorted_axis(Axis(0)), arr1(&[true, false, false]));
+
+    let mut b = a.clone();
+    b.sort_axis(Axis(0));
+    assert!(b.is_sorted_axis(Axis(0)).iter().all(|&x| x));
+
+    assert!(arr1(&[1., 2., 3.]).is_sorted());
+    assert!(!arr1(&[1., f64::NAN, 3.]).is_sorted());
+    assert!(arr1(&[f64::NAN]).is_sorted());
+    assert!(Array1::<i32>::zeros(0).is_sorted());
+    assert_eq!(Array2::<i32>::zeros((0, 2)).is_sorted_axis(Axis(0)), arr1(&[true, true]));
+}
+
+#[test]
+fn argsort_axis() {
+    let a = arr2(&[[3, 1, 2], [9, 7, 8], [6, 5, 4]]);
+    let rows = a.argsort_axis(Axis(1));
+    assert_eq!(rows, arr2(&[[1, 2, 0], [1, 2, 0], [2, 1, 0]]));
+    let mut sorted = a.clone();
+    sorted.sort_axis(Axis(1));
+    assert_eq!(a.take_along_axis(&rows, Axis(1)), sorted);
+
+    let cols = a.argsort_axis(Axis(0));
+    assert_eq!(cols, arr2(&[[0, 0, 0], [2, 2, 2], [1, 1, 1]]));
+
+    // stable: ties keep their original order
+    let b = arr1(&[(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+    assert_eq!(b.argsort_axis_by_key(Axis(0), |&(key, _)| key), arr1(&[1, 3, 0, 2]));
+
+    let c = arr1(&[0.5, -1., 2., 0.]);
+    assert_eq!(
+        c.argsort_axis_by(Axis(0), |x, y| y.partial_cmp(x).unwrap()),
+        arr1(&[2, 0, 3, 1])
+    );
+
+    // non-contiguous lanes, and reordering a parallel array
+    let labels = arr2(&[["c", "a", "b"], ["z", "x", "y"], ["f", "e", "d"]]);
+    let perm = a.t().argsort_axis(Axis(0));
+    assert_eq!(perm, rows.t());
+    assert_eq!(
+        labels.take_along_axis(&perm.t(), Axis(1)),
+        arr2(&[["a", "b", "c"], ["x", "y", "z"], ["d", "e", "f"]])
+    );
+
+    assert_eq!(Array2::<i32>::zeros((0, 3)).argsort_axis(Axis(0)).shape(), &[0, 3]);
+}
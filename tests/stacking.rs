@@ -1,4 +1,5 @@
 use ndarray::{arr2, arr3, aview1, concatenate, stack, Array2, Axis, ErrorKind, Ix1};
+use ndarray::{block_diag, elementwise_max, elementwise_mean, elementwise_min, stack_reduce};
 
 #[test]
 fn concatenating() {
@@ -44,3 +45,86 @@ fn stacking() {
     let res: Result<Array2<f64>, _> = ndarray::stack::<_, Ix1>(Axis(0), &[]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
 }
+
+#[test]
+fn elementwise_reductions() {
+    let a = arr2(&[[1., 6.], [-2., 0.]]);
+    let b = arr2(&[[3., 2.], [-1., 4.]]);
+    let c = arr2(&[[2., 7.], [-3., 2.]]);
+    let arrays = [a.view(), b.view(), c.view()];
+    assert_eq!(elementwise_max(&arrays).unwrap(), arr2(&[[3., 7.], [-1., 4.]]));
+    assert_eq!(elementwise_min(&arrays).unwrap(), arr2(&[[1., 2.], [-3., 0.]]));
+    assert_eq!(elementwise_mean(&arrays).unwrap(), arr2(&[[2., 5.], [-2., 2.]]));
+    assert_eq!(elementwise_max(&arrays[..1]).unwrap(), a);
+
+    // NaN propagates
+    let d = arr2(&[[f64::NAN, 0.], [0., 0.]]);
+    let res = elementwise_max(&[a.view(), d.view(), b.view()]).unwrap();
+    assert!(res[[0, 0]].is_nan());
+    assert_eq!(res[[0, 1]], 6.);
+    assert!(elementwise_min(&[d.view(), a.view()]).unwrap()[[0, 0]].is_nan());
+
+    let e = arr2(&[[1., 2., 3.]]);
+    let res = elementwise_mean(&[a.view(), e.view()]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+
+    let res = elementwise_max::<f64, Ix1>(&[]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn stack_reducing() {
+    let arrays: Vec<Array2<f64>> = (0..5)
+        .map(|k| Array2::from_shape_fn((3, 4), |(i, j)| (k * k) as f64 + (i * j) as f64 - 2.))
+        .collect();
+    let views: Vec<_> = arrays.iter().map(|a| a.view()).collect();
+    let mean = stack_reduce(Axis(2), &views, |xs| {
+        xs.iter().copied().sum::<f64>() / xs.len() as f64
+    })
+    .unwrap();
+    assert_eq!(mean, stack(Axis(0), &views).unwrap().mean_axis(Axis(0)).unwrap());
+
+    // elements are passed in order, also for non-standard layouts
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let b = arr2(&[[10, 20], [30, 40], [50, 60]]);
+    let res = stack_reduce(Axis(0), &[a.t(), b.view()], |xs| (*xs[0], *xs[1])).unwrap();
+    assert_eq!(res, arr2(&[[(1, 10), (4, 20)], [(2, 30), (5, 40)], [(3, 50), (6, 60)]]));
+
+    let res = stack_reduce(Axis(3), &views, |xs| xs.len());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::OutOfBounds);
+    let res = stack_reduce(Axis(0), &[a.view(), b.view()], |xs| xs.len());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    let res = stack_reduce(Axis(0), &[] as &[ndarray::ArrayView1<f64>], |xs| xs.len());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn block_diagonal() {
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    let b = arr2(&[[5., 6.], [7., 8.]]);
+    assert_eq!(
+        block_diag(&[a.view(), b.view()]),
+        arr2(&[
+            [1., 2., 0., 0.],
+            [3., 4., 0., 0.],
+            [0., 0., 5., 6.],
+            [0., 0., 7., 8.]
+        ])
+    );
+
+    // rectangular, transposed and empty blocks
+    let c = arr2(&[[1, 2, 3]]);
+    let d = arr2(&[[4, 5], [6, 7]]);
+    let empty = Array2::<i32>::zeros((0, 2));
+    assert_eq!(
+        block_diag(&[c.t(), empty.view(), d.view()]),
+        arr2(&[
+            [1, 0, 0, 0, 0],
+            [2, 0, 0, 0, 0],
+            [3, 0, 0, 0, 0],
+            [0, 0, 0, 4, 5],
+            [0, 0, 0, 6, 7]
+        ])
+    );
+    assert_eq!(block_diag::<i32>(&[]).shape(), &[0, 0]);
+}
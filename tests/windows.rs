@@ -152,3 +152,42 @@ fn test_window_neg_stride() {
         answer.iter()
     );
 }
+
+#[test]
+fn for_each_window_mut_visits_windows_in_order() {
+    let mut a = Array::from_iter(10..22).into_shape((3, 4)).unwrap();
+    let windows: Vec<_> = a.windows((2, 3)).into_iter().map(|w| w.to_owned()).collect();
+    let mut visited = Vec::new();
+    a.for_each_window_mut((2, 3), |w| visited.push(w.to_owned()));
+    assert_eq!(visited, windows);
+
+    // each element is incremented once for each window that contains it
+    let mut b = Array::<i32, _>::zeros((3, 4));
+    b.for_each_window_mut((2, 2), |mut w| w += 1);
+    assert_eq!(b, arr2(&[[1, 2, 2, 1], [2, 4, 4, 2], [1, 2, 2, 1]]));
+}
+
+#[test]
+fn for_each_window_mut_smoothing() {
+    // in-place smoothing sees the values written for the previous windows
+    let mut a = arr1(&[0., 3., 0., 3., 0.]);
+    a.for_each_window_mut(3, |mut w| w[1] = w.sum() / 3.);
+    assert_eq!(a, arr1(&[0., 1., 4. / 3., (4. / 3. + 3.) / 3., 0.]));
+
+    // negative strides
+    let mut b = Array::from_iter(0..6);
+    b.invert_axis(Axis(0));
+    let mut firsts = Vec::new();
+    b.for_each_window_mut(4, |w| firsts.push(w[0]));
+    assert_eq!(firsts, vec![5, 4, 3]);
+
+    let mut called = false;
+    b.for_each_window_mut(7, |_| called = true);
+    assert!(!called);
+}
+
+#[test]
+#[should_panic]
+fn for_each_window_mut_zero_size() {
+    Array::<i32, _>::zeros((2, 2)).for_each_window_mut((0, 1), |_| {});
+}
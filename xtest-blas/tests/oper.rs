@@ -101,6 +101,36 @@ where
         .unwrap()
 }
 
+#[test]
+fn scaled_add_axpy() {
+    let alpha = -2.5;
+    for &(m, n) in &[(4, 4), (8, 8), (17, 15), (67, 63)] {
+        let b = range_mat64(m, n);
+        // contiguous, transposed and non-contiguous layouts
+        for &(transpose, step) in &[(false, 1), (true, 1), (false, 2)] {
+            let mut a = range_mat64(m, n);
+            a.mapv_inplace(|x| x * x);
+            let mut answer = a.clone();
+            let (mut av, mut bv) = (a.slice_mut(s![.., ..;step]), b.slice(s![.., ..;step]));
+            let mut answerv = answer.slice_mut(s![.., ..;step]);
+            if transpose {
+                av = av.reversed_axes();
+                bv = bv.reversed_axes();
+                answerv = answerv.reversed_axes();
+            }
+            answerv.zip_mut_with(&bv, |y, &x| *y += alpha * x);
+            av.scaled_add(alpha, &bv);
+            assert_relative_eq!(a, answer, epsilon = 1e-12, max_relative = 1e-7);
+        }
+
+        let mut a = range_mat(m, n);
+        let answer = &a + &(2. * &a);
+        let c = a.clone();
+        a.scaled_add(2., &c);
+        assert_relative_eq!(a, answer, epsilon = 1e-4, max_relative = 1e-6);
+    }
+}
+
 // Check that matrix multiplication of contiguous matrices returns a
 // matrix with the same order
 #[test]